@@ -2,6 +2,29 @@ use std::env;
 use std::path::PathBuf;
 
 fn main() {
+    // the in-process bindings use thiscall/fastcall and the windows API, so they're
+    // only built for 32-bit windows, everything else compiles on any target
+    println!("cargo:rustc-check-cfg=cfg(game_bindings)");
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    if env::var_os("CARGO_FEATURE_GAME").is_some() && target_os == "windows" && target_arch == "x86"
+    {
+        println!("cargo:rustc-cfg=game_bindings");
+    }
+
+    // regenerate the FMOD types with bindgen, otherwise the checked in
+    // `src/fmod/bindings.rs` is used and libclang isn't needed
+    #[cfg(feature = "regenerate-bindings")]
+    generate_bindings();
+
+    // link libraries
+    if env::var_os("CARGO_FEATURE_LINK_FMOD").is_some() {
+        link_libs();
+    }
+}
+
+#[cfg(feature = "regenerate-bindings")]
+fn generate_bindings() {
     let out_path = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR enviroment variable not set"));
 
     let bindings = bindgen::Builder::default()
@@ -12,6 +35,8 @@ fn main() {
         .header("src/fmod/fmod-headers/include/fmod_dsp_effects.h")
         .header("src/fmod/fmod-headers/include/fmod_errors.h")
         .header("src/fmod/fmod-headers/include/fmod_output.h")
+        // functions are declared in `src/fmod/functions.rs`, so they can be loaded at runtime
+        .blocklist_function(".*")
         .prepend_enum_name(false)
         .derive_debug(true);
 
@@ -20,13 +45,14 @@ fn main() {
         .expect("unable to generate bindings")
         .write_to_file(out_path.join("bindings.rs"))
         .expect("unable to write bindings");
-
-    // link libraries
-    link_libs();
 }
 
-#[cfg(windows)]
 fn link_libs() {
+    if env::var("CARGO_CFG_TARGET_OS").as_deref() != Ok("windows") {
+        println!("cargo:warning=the `link-fmod` feature only works when targeting windows");
+        return;
+    }
+
     let path = PathBuf::from(
         std::env::var("CARGO_MANIFEST_DIR")
             .expect("CARGO_MANIFEST_DIR enviroment variable not set"),
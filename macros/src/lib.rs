@@ -0,0 +1,64 @@
+//! Procedural macros for the `geometrydash` crate, use them through it.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, ItemFn, ReturnType};
+
+/// Turns a function that creates the mod into the entry point of the DLL.
+///
+/// The function takes no arguments and returns a type that implements
+/// `geometrydash::modding::Mod`. It is called on the game's main thread once the
+/// game has started, not in `DllMain`.
+///
+/// ```ignore
+/// #[geometrydash::main]
+/// fn main() -> MyMod {
+///     MyMod::default()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn main(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::TokenStream::from(attr).span(),
+            "#[geometrydash::main] doesn't take arguments",
+        )
+        .into_compile_error()
+        .into();
+    }
+    let func = parse_macro_input!(item as ItemFn);
+    let sig = &func.sig;
+    let error = if !sig.inputs.is_empty() {
+        Some((
+            sig.inputs.span(),
+            "the mod entry point can't take arguments",
+        ))
+    } else if sig.asyncness.is_some() {
+        Some((sig.span(), "the mod entry point can't be async"))
+    } else if !sig.generics.params.is_empty() {
+        Some((sig.generics.span(), "the mod entry point can't be generic"))
+    } else if matches!(sig.output, ReturnType::Default) {
+        Some((sig.span(), "the mod entry point must return the mod"))
+    } else {
+        None
+    };
+    if let Some((span, message)) = error {
+        return syn::Error::new(span, message).into_compile_error().into();
+    }
+
+    let name = &sig.ident;
+    quote! {
+        #func
+
+        #[no_mangle]
+        #[allow(non_snake_case)]
+        extern "system" fn DllMain(
+            module: ::geometrydash::Ptr,
+            reason: u32,
+            _reserved: *mut ::core::ffi::c_void,
+        ) -> i32 {
+            ::geometrydash::modding::dll_main(module, reason, env!("CARGO_PKG_NAME"), #name)
+        }
+    }
+    .into()
+}
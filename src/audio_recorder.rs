@@ -0,0 +1,411 @@
+use crate::{
+    fmod::{
+        self, Dsp, FMOD_ChannelGroup_RemoveDSP, FMOD_DSP_GetUserData, FMOD_DSP_Release,
+        FMOD_DSP_SetUserData, FMOD_System_CreateDSP, FMOD_CHANNELGROUP, FMOD_DSP,
+        FMOD_DSP_DESCRIPTION, FMOD_DSP_STATE, FMOD_OK, FMOD_PLUGIN_SDK_VERSION, FMOD_RESULT,
+    },
+    ring_buffer::RingBuffer,
+    wav::{SampleFormat, WavError, WavWriter},
+    FMODAudioEngine,
+};
+use std::{
+    ffi::{c_int, c_uint, c_void},
+    path::{Path, PathBuf},
+    ptr::NonNull,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex, TryLockError,
+    },
+    thread::JoinHandle,
+    time::Duration,
+};
+
+/// Errors that can happen while recording audio.
+#[derive(Debug)]
+pub enum RecorderError {
+    /// An FMOD call failed.
+    Fmod(fmod::Error),
+    /// Writing the WAV file failed.
+    Wav(WavError),
+    /// [`AudioRecorder::attach`] wasn't called.
+    NotAttached,
+    /// A recording is already running.
+    AlreadyRecording,
+    /// The mixer has more channels than the recorder supports.
+    TooManyChannels(usize),
+}
+
+impl std::fmt::Display for RecorderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fmod(e) => write!(f, "fmod error: {e}"),
+            Self::Wav(e) => write!(f, "{e}"),
+            Self::NotAttached => write!(f, "recorder is not attached to the mixer"),
+            Self::AlreadyRecording => write!(f, "already recording"),
+            Self::TooManyChannels(channels) => write!(
+                f,
+                "the mixer has {channels} channels, at most {} are supported",
+                AudioRecorder::MAX_CHANNELS
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RecorderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Fmod(e) => Some(e),
+            Self::Wav(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<fmod::Error> for RecorderError {
+    fn from(value: fmod::Error) -> Self {
+        Self::Fmod(value)
+    }
+}
+
+impl From<WavError> for RecorderError {
+    fn from(value: WavError) -> Self {
+        Self::Wav(value)
+    }
+}
+
+/// State shared between the mixer thread and the writer thread.
+struct Shared {
+    buffer: Mutex<RingBuffer<f32>>,
+    recording: AtomicBool,
+    channels: usize,
+    /// Samples the mixer thread couldn't push because the buffer was locked.
+    skipped: AtomicUsize,
+    /// Part of `skipped` not replaced with silence yet, the mixer thread pushes
+    /// it before its next samples to keep the file in sync.
+    gap: AtomicUsize,
+}
+
+/// Records the game's final audio mix to a WAV file.
+///
+/// A pass-through DSP is inserted at the head of the master channel group, so
+/// everything the game plays (music, sound effects, clicks played through
+/// FMOD) is captured. The mixer thread only copies samples into a ring buffer,
+/// a separate thread writes them to disk.
+///
+/// ```no_run
+/// let mut recorder = geometrydash::AudioRecorder::new();
+/// recorder.attach(&geometrydash::FMODAudioEngine::shared()).unwrap();
+/// // from a PlayLayer::init / resetLevel hook:
+/// recorder.on_level_start("showcase.wav").unwrap();
+/// // from a PlayLayer::levelComplete hook:
+/// recorder.on_level_complete().unwrap();
+/// ```
+pub struct AudioRecorder {
+    format: SampleFormat,
+    attached: Option<Attached>,
+    writer: Option<JoinHandle<Result<(), WavError>>>,
+}
+
+struct Attached {
+    shared: Arc<Shared>,
+    dsp: NonNull<FMOD_DSP>,
+    group: NonNull<FMOD_CHANNELGROUP>,
+    sample_rate: u32,
+}
+
+impl Default for AudioRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AudioRecorder {
+    /// How much audio the ring buffer can hold before samples are dropped.
+    const BUFFER_SECONDS: usize = 2;
+
+    /// How often the writer thread flushes the ring buffer to disk.
+    const FLUSH_INTERVAL: Duration = Duration::from_millis(20);
+
+    /// Most mixer channels that can be recorded.
+    pub const MAX_CHANNELS: usize = 32;
+
+    /// Creates a recorder that writes 16-bit WAV files.
+    pub const fn new() -> Self {
+        Self {
+            format: SampleFormat::Int16,
+            attached: None,
+            writer: None,
+        }
+    }
+
+    /// Sets the sample encoding of recordings started after this call.
+    pub fn set_format(&mut self, format: SampleFormat) {
+        self.format = format;
+    }
+
+    /// Inserts the capture DSP into the game's master channel group.
+    ///
+    /// Fails with [`RecorderError::TooManyChannels`] if the mixer has more
+    /// than [`AudioRecorder::MAX_CHANNELS`] channels.
+    pub fn attach(&mut self, engine: &FMODAudioEngine) -> Result<(), RecorderError> {
+        if self.attached.is_some() {
+            return Ok(());
+        }
+        let system = engine.fmod_system().ok_or(fmod::Error::Uninitialized)?;
+        let sample_rate = system.sample_rate()?;
+        let channels = system.output_channels()?.max(1) as usize;
+        if channels > Self::MAX_CHANNELS {
+            return Err(RecorderError::TooManyChannels(channels));
+        }
+        let group = system.master_channel_group()?;
+
+        let shared = Arc::new(Shared {
+            buffer: Mutex::new(RingBuffer::new(
+                sample_rate as usize * channels * Self::BUFFER_SECONDS,
+            )),
+            recording: AtomicBool::new(false),
+            channels,
+            skipped: AtomicUsize::new(0),
+            gap: AtomicUsize::new(0),
+        });
+
+        let mut desc: FMOD_DSP_DESCRIPTION = unsafe { std::mem::zeroed() };
+        desc.pluginsdkversion = FMOD_PLUGIN_SDK_VERSION;
+        for (dst, src) in desc.name.iter_mut().zip(b"geometrydash recorder") {
+            *dst = *src as _;
+        }
+        desc.version = 1;
+        desc.numinputbuffers = 1;
+        desc.numoutputbuffers = 1;
+        desc.read = Some(read_callback);
+
+        let mut dsp = std::ptr::null_mut();
+        fmod::Error::check(unsafe { FMOD_System_CreateDSP(system.as_raw(), &desc, &mut dsp) })?;
+        let dsp = NonNull::new(dsp).ok_or(fmod::Error::InvalidHandle)?;
+        unsafe { FMOD_DSP_SetUserData(dsp.as_ptr(), Arc::as_ptr(&shared) as *mut c_void) };
+        if let Err(e) = group.add_dsp(0, unsafe { Dsp::from_raw(dsp.as_ptr()) }.unwrap()) {
+            unsafe { FMOD_DSP_Release(dsp.as_ptr()) };
+            return Err(e.into());
+        }
+
+        self.attached = Some(Attached {
+            shared,
+            dsp,
+            group: NonNull::new(group.as_raw()).unwrap(),
+            sample_rate,
+        });
+        Ok(())
+    }
+
+    /// Stops any recording and removes the capture DSP from the mixer.
+    ///
+    /// If removing the DSP fails the recorder stays attached, so this can be
+    /// retried.
+    pub fn detach(&mut self) -> Result<(), RecorderError> {
+        let stopped = self.stop();
+        if let Some(attached) = &self.attached {
+            // the DSP userdata points into `shared`, so it can only be dropped
+            // once the mixer is done with the DSP
+            unsafe {
+                fmod::Error::check(FMOD_ChannelGroup_RemoveDSP(
+                    attached.group.as_ptr(),
+                    attached.dsp.as_ptr(),
+                ))?;
+                if let Err(e) = fmod::Error::check(FMOD_DSP_Release(attached.dsp.as_ptr())) {
+                    // out of the mixer but still alive, leak `shared` to be safe
+                    self.leak();
+                    return Err(e.into());
+                }
+            }
+            self.attached = None;
+        }
+        stopped
+    }
+
+    /// Forgets the capture DSP without dropping the state its userdata points to.
+    fn leak(&mut self) {
+        if let Some(attached) = self.attached.take() {
+            std::mem::forget(attached.shared);
+        }
+    }
+
+    /// Returns whether the capture DSP is in the mixer.
+    #[inline]
+    pub fn is_attached(&self) -> bool {
+        self.attached.is_some()
+    }
+
+    /// Returns whether a recording is running.
+    #[inline]
+    pub fn is_recording(&self) -> bool {
+        self.writer.is_some()
+    }
+
+    /// Number of samples lost because the writer thread couldn't keep up.
+    ///
+    /// Samples the mixer couldn't hand over while the writer held the buffer
+    /// count too, they are written as silence.
+    pub fn dropped_samples(&self) -> usize {
+        self.attached.as_ref().map_or(0, |a| {
+            let dropped = a
+                .shared
+                .buffer
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .dropped();
+            dropped + a.shared.skipped.load(Ordering::Relaxed)
+        })
+    }
+
+    /// Starts recording to a new WAV file at `path`.
+    pub fn start<P: AsRef<Path>>(&mut self, path: P) -> Result<(), RecorderError> {
+        let attached = self.attached.as_ref().ok_or(RecorderError::NotAttached)?;
+        if self.writer.is_some() {
+            return Err(RecorderError::AlreadyRecording);
+        }
+
+        let shared = attached.shared.clone();
+        shared
+            .buffer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+        shared.skipped.store(0, Ordering::Relaxed);
+        shared.gap.store(0, Ordering::Relaxed);
+        let mut writer = WavWriter::create(
+            path,
+            attached.sample_rate,
+            shared.channels as u16,
+            self.format,
+        )?;
+        shared.recording.store(true, Ordering::Release);
+
+        self.writer = Some(std::thread::spawn(move || {
+            let mut samples = Vec::new();
+            loop {
+                // read the flag before draining, so nothing pushed before stop() is lost
+                let recording = shared.recording.load(Ordering::Acquire);
+                shared
+                    .buffer
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .drain_into(&mut samples);
+                writer.write_samples(&samples)?;
+                samples.clear();
+
+                if !recording {
+                    writer.finalize()?;
+                    return Ok(());
+                }
+                std::thread::sleep(Self::FLUSH_INTERVAL);
+            }
+        }));
+        Ok(())
+    }
+
+    /// Stops recording and waits for the WAV file to be finalized.
+    pub fn stop(&mut self) -> Result<(), RecorderError> {
+        let Some(writer) = self.writer.take() else {
+            return Ok(());
+        };
+        if let Some(attached) = &self.attached {
+            attached.shared.recording.store(false, Ordering::Release);
+        }
+        match writer.join() {
+            Ok(result) => result.map_err(RecorderError::from),
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+
+    /// Starts a new recording when a level starts, finishing the previous one if needed.
+    ///
+    /// Call this from a level start hook, e.g. `PlayLayer::init`.
+    pub fn on_level_start<P: Into<PathBuf>>(&mut self, path: P) -> Result<(), RecorderError> {
+        self.stop()?;
+        self.start(path.into())
+    }
+
+    /// Finishes the recording when the level is completed.
+    ///
+    /// Call this from a `PlayLayer::levelComplete` hook.
+    pub fn on_level_complete(&mut self) -> Result<(), RecorderError> {
+        self.stop()
+    }
+}
+
+impl Drop for AudioRecorder {
+    fn drop(&mut self) {
+        if self.detach().is_err() {
+            // the DSP may still be in the mixer
+            self.leak();
+        }
+    }
+}
+
+/// Pass-through DSP read callback, runs on the FMOD mixer thread.
+unsafe extern "system" fn read_callback(
+    dsp_state: *mut FMOD_DSP_STATE,
+    inbuffer: *mut f32,
+    outbuffer: *mut f32,
+    length: c_uint,
+    inchannels: c_int,
+    outchannels: *mut c_int,
+) -> FMOD_RESULT {
+    if inbuffer.is_null() || outbuffer.is_null() || outchannels.is_null() || length == 0 {
+        return FMOD_OK;
+    }
+    let frames = length as usize;
+    let in_channels = inchannels.max(0) as usize;
+    let out_channels = (*outchannels).max(0) as usize;
+    let input = std::slice::from_raw_parts(inbuffer, frames * in_channels);
+    let output = std::slice::from_raw_parts_mut(outbuffer, frames * out_channels);
+
+    if in_channels == out_channels {
+        output.copy_from_slice(input);
+    } else {
+        for (out_frame, in_frame) in output
+            .chunks_exact_mut(out_channels.max(1))
+            .zip(input.chunks_exact(in_channels.max(1)))
+        {
+            for (ch, out) in out_frame.iter_mut().enumerate() {
+                *out = in_frame.get(ch).copied().unwrap_or(0.0);
+            }
+        }
+    }
+
+    let mut userdata = std::ptr::null_mut();
+    FMOD_DSP_GetUserData((*dsp_state).instance as *mut FMOD_DSP, &mut userdata);
+    let Some(shared) = (userdata as *const Shared).as_ref() else {
+        return FMOD_OK;
+    };
+    if !shared.recording.load(Ordering::Acquire) {
+        return FMOD_OK;
+    }
+
+    // never wait for the writer thread here, a late mixer stalls the game audio
+    let mut buffer = match shared.buffer.try_lock() {
+        Ok(buffer) => buffer,
+        Err(TryLockError::Poisoned(e)) => e.into_inner(),
+        Err(TryLockError::WouldBlock) => {
+            let samples = frames * shared.channels;
+            shared.skipped.fetch_add(samples, Ordering::Relaxed);
+            shared.gap.fetch_add(samples, Ordering::Relaxed);
+            return FMOD_OK;
+        }
+    };
+    buffer.push_repeated(0.0, shared.gap.swap(0, Ordering::Relaxed));
+    if in_channels == shared.channels {
+        buffer.push_slice(input);
+    } else {
+        // the mixer format changed under us, remap to the file's channel count
+        let mut frame = [0.0f32; AudioRecorder::MAX_CHANNELS];
+        let frame = &mut frame[..shared.channels];
+        for in_frame in input.chunks_exact(in_channels.max(1)) {
+            for (ch, out) in frame.iter_mut().enumerate() {
+                *out = in_frame.get(ch).copied().unwrap_or(0.0);
+            }
+            buffer.push_slice(frame);
+        }
+    }
+    FMOD_OK
+}
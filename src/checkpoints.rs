@@ -0,0 +1,288 @@
+//! Practice mode checkpoints placed automatically.
+//!
+//! [`AutoCheckpoints`] looks at the player every frame and decides when to
+//! place a checkpoint, following one or more [`Policy`]. In the game,
+//! `AutoCheckpoints::update_level` reads the state of a `PlayLayer` and places
+//! the checkpoint with `PlayLayer::create_checkpoint`:
+//!
+//! ```ignore
+//! use geometrydash::checkpoints::{AutoCheckpoints, Policy};
+//!
+//! let mut auto = AutoCheckpoints::new(vec![
+//!     Policy::Interval(5.0),
+//!     Policy::ModeChange,
+//!     Policy::SafeGround { duration: 0.25, min_gap: 1.0 },
+//! ]);
+//!
+//! // every frame, e.g. in `Mod::on_tick`
+//! auto.update_level(play_layer);
+//! ```
+
+use crate::GameMode;
+
+/// When to place a checkpoint.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Policy {
+    /// Every this many seconds of level time.
+    Interval(f64),
+    /// Right after the player changes gamemode.
+    ModeChange,
+    /// Once the player has been on the ground for `duration` seconds, at
+    /// least `min_gap` seconds after the previous checkpoint.
+    SafeGround { duration: f64, min_gap: f64 },
+}
+
+/// What [`AutoCheckpoints`] needs to know about the player in a frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayerState {
+    /// Level time in seconds.
+    pub time: f64,
+    pub mode: GameMode,
+    pub on_ground: bool,
+    /// Dead players can't get checkpoints.
+    pub is_dead: bool,
+}
+
+/// Decides when to place checkpoints, see the [module docs](self).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoCheckpoints {
+    policies: Vec<Policy>,
+    enabled: bool,
+    /// Level time of the last checkpoint (or of the start of the attempt).
+    last_placed: f64,
+    last_time: f64,
+    last_mode: Option<GameMode>,
+    /// Level time the player landed at.
+    ground_since: Option<f64>,
+}
+
+impl AutoCheckpoints {
+    pub fn new(policies: Vec<Policy>) -> Self {
+        Self {
+            policies,
+            enabled: true,
+            last_placed: 0.0,
+            last_time: 0.0,
+            last_mode: None,
+            ground_since: None,
+        }
+    }
+
+    #[inline]
+    pub fn policies(&self) -> &[Policy] {
+        &self.policies
+    }
+
+    pub fn set_policies(&mut self, policies: Vec<Policy>) {
+        self.policies = policies;
+    }
+
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Forgets everything about the previous frames, e.g. after leaving a level.
+    pub fn reset(&mut self) {
+        self.last_placed = 0.0;
+        self.last_time = 0.0;
+        self.last_mode = None;
+        self.ground_since = None;
+    }
+
+    /// Updates the state with a frame and returns the policy that wants a
+    /// checkpoint placed now, if any. Call [`AutoCheckpoints::placed`] once
+    /// it was placed.
+    ///
+    /// A time lower than the previous frame's (a restart, or a respawn at a
+    /// checkpoint) starts counting from there.
+    pub fn update(&mut self, state: PlayerState) -> Option<Policy> {
+        if state.time < self.last_time {
+            self.last_placed = state.time;
+            self.last_mode = None;
+            self.ground_since = None;
+        }
+        self.last_time = state.time;
+        let mode_changed = self.last_mode.is_some_and(|mode| mode != state.mode);
+        self.last_mode = Some(state.mode);
+        self.ground_since = match self.ground_since {
+            Some(since) if state.on_ground => Some(since),
+            _ => state.on_ground.then_some(state.time),
+        };
+        if !self.enabled || state.is_dead {
+            return None;
+        }
+
+        let since_last = state.time - self.last_placed;
+        self.policies.iter().copied().find(|policy| match *policy {
+            Policy::Interval(interval) => since_last >= interval,
+            Policy::ModeChange => mode_changed,
+            Policy::SafeGround { duration, min_gap } => {
+                since_last >= min_gap
+                    && self
+                        .ground_since
+                        .is_some_and(|since| state.time - since >= duration)
+            }
+        })
+    }
+
+    /// Records that a checkpoint was placed at `time`.
+    pub fn placed(&mut self, time: f64) {
+        self.last_placed = time;
+    }
+}
+
+#[cfg(game_bindings)]
+mod game {
+    use super::*;
+    use crate::{impl_addr_funcs, read_ptr, AddressUtils, GameObject, PlayLayer, Ptr};
+
+    // https://github.com/maxnut/gd.h/blob/436af2c6440a7efd1ba4720e48429fddaaf298e4/layers_scenes_transitions_nodes/CheckpointObject.h
+    //
+    // GameObject* CheckpointObject.gameObject = CheckpointObject + 0xEC
+
+    /// A practice mode checkpoint, see [`PlayLayer::checkpoints`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct CheckpointObject {
+        address: Ptr,
+    }
+
+    impl CheckpointObject {
+        #[inline(always)]
+        pub fn from_address(address: Ptr) -> Self {
+            Self { address }
+        }
+
+        /// The checkpoint crystal shown in the level.
+        #[inline(always)]
+        pub fn object(&self) -> GameObject {
+            GameObject::from_address(unsafe { read_ptr(self.address + 0xEC) })
+        }
+
+        /// Position of the checkpoint in the level, `None` if it has no crystal.
+        pub fn position(&self) -> Option<(f32, f32)> {
+            let object = self.object().to_option()?;
+            Some((object.x(), object.y()))
+        }
+    }
+
+    impl_addr_funcs!(CheckpointObject);
+    crate::rtti::impl_rtti_class!(CheckpointObject, "CheckpointObject");
+
+    impl PlayerState {
+        /// Reads the state of player 1.
+        pub fn from_level(play_layer: PlayLayer) -> Self {
+            let player = play_layer.player1();
+            Self {
+                time: play_layer.time(),
+                mode: player.game_mode(),
+                on_ground: player.is_on_ground(),
+                is_dead: play_layer.is_dead_raw(),
+            }
+        }
+    }
+
+    impl AutoCheckpoints {
+        /// Updates with the state of the level and places a checkpoint if a
+        /// policy wants one. Only does anything in practice mode.
+        ///
+        /// Returns the placed checkpoint.
+        pub fn update_level(&mut self, play_layer: PlayLayer) -> Option<CheckpointObject> {
+            if play_layer.is_null() || !play_layer.is_practice_mode() {
+                return None;
+            }
+            let state = PlayerState::from_level(play_layer);
+            self.update(state)?;
+            let checkpoint = play_layer.create_checkpoint().to_option()?;
+            self.placed(state.time);
+            Some(checkpoint)
+        }
+    }
+}
+
+#[cfg(game_bindings)]
+pub use game::CheckpointObject;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(time: f64, mode: GameMode, on_ground: bool) -> PlayerState {
+        PlayerState {
+            time,
+            mode,
+            on_ground,
+            is_dead: false,
+        }
+    }
+
+    /// Runs 60 fps frames and returns the times checkpoints were placed at.
+    fn run(auto: &mut AutoCheckpoints, frames: impl Iterator<Item = PlayerState>) -> Vec<f64> {
+        let mut placed = Vec::new();
+        for frame in frames {
+            if auto.update(frame).is_some() {
+                auto.placed(frame.time);
+                placed.push(frame.time);
+            }
+        }
+        placed
+    }
+
+    #[test]
+    fn interval_and_mode_change() {
+        let mut auto = AutoCheckpoints::new(vec![Policy::Interval(1.0), Policy::ModeChange]);
+        let frames = (0..=150).map(|frame| {
+            let time = frame as f64 / 60.0;
+            let mode = if frame < 90 {
+                GameMode::Cube
+            } else {
+                GameMode::Ship
+            };
+            state(time, mode, false)
+        });
+        let placed: Vec<_> = run(&mut auto, frames)
+            .into_iter()
+            .map(|t| (t * 60.0).round() as i32)
+            .collect();
+        // at 1 s, at the portal, 1 s after the portal
+        assert_eq!(placed, [60, 90, 150]);
+
+        // dying stops it, restarting starts counting again
+        let dead = PlayerState {
+            is_dead: true,
+            ..state(10.0, GameMode::Ship, false)
+        };
+        assert_eq!(auto.update(dead), None);
+        assert_eq!(auto.update(state(0.0, GameMode::Cube, false)), None);
+        assert_eq!(auto.update(state(0.5, GameMode::Cube, false)), None);
+        assert_eq!(
+            auto.update(state(1.0, GameMode::Cube, false)),
+            Some(Policy::Interval(1.0))
+        );
+    }
+
+    #[test]
+    fn safe_ground() {
+        let mut auto = AutoCheckpoints::new(vec![Policy::SafeGround {
+            duration: 0.1,
+            min_gap: 1.0,
+        }]);
+        // on the ground, except for a jump between 0.5 s and 1.5 s
+        let frames = (0..=180).map(|frame| {
+            let time = frame as f64 / 60.0;
+            state(time, GameMode::Cube, !(0.5..1.5).contains(&time))
+        });
+        let placed: Vec<_> = run(&mut auto, frames)
+            .into_iter()
+            .map(|t| (t * 60.0).round() as i32)
+            .collect();
+        // the jump delays the first one until 0.1 s after landing at 1.5 s, the gap
+        // delays the next one until 1 s after that
+        assert_eq!(placed, [96, 156]);
+    }
+}
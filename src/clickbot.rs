@@ -0,0 +1,427 @@
+//! Offline clickbot audio renderer.
+//!
+//! Mixes click sounds at input timestamps into a WAV file, so showcase videos
+//! can have clicks that are perfectly in sync with the inputs. Everything here
+//! is pure Rust and does not touch the game.
+
+use crate::wav::{self, AudioBuffer, SampleFormat, WavError, WavWriter};
+use std::path::Path;
+
+/// A single timed input, e.g. taken from a replay.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClickAction {
+    /// Time of the input in seconds since the start of the level.
+    pub time: f64,
+    /// `true` for a press, `false` for a release.
+    pub down: bool,
+    /// Whether the input belongs to player 2.
+    pub player2: bool,
+}
+
+/// Which group of samples a click is picked from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClickType {
+    Click,
+    Release,
+    SoftClick,
+    HardClick,
+}
+
+/// A set of click samples, converted to a common sample rate and channel count.
+#[derive(Debug, Clone, Default)]
+pub struct ClickPack {
+    pub clicks: Vec<AudioBuffer>,
+    pub releases: Vec<AudioBuffer>,
+    pub soft_clicks: Vec<AudioBuffer>,
+    pub hard_clicks: Vec<AudioBuffer>,
+}
+
+impl ClickPack {
+    /// Loads a clickpack from a folder.
+    ///
+    /// The folder is expected to contain `clicks`, `releases`, `softclicks` and
+    /// `hardclicks` subfolders with WAV files. Only `clicks` is required, missing
+    /// groups fall back to regular clicks (or silence for releases).
+    pub fn from_dir<P: AsRef<Path>>(
+        dir: P,
+        sample_rate: u32,
+        channels: u16,
+    ) -> Result<Self, ClickbotError> {
+        let dir = dir.as_ref();
+        let load = |name: &str| -> Result<Vec<AudioBuffer>, ClickbotError> {
+            let path = dir.join(name);
+            if !path.is_dir() {
+                return Ok(Vec::new());
+            }
+            let mut paths = std::fs::read_dir(&path)
+                .map_err(WavError::Io)?
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|p| {
+                    p.extension()
+                        .is_some_and(|ext| ext.eq_ignore_ascii_case("wav"))
+                })
+                .collect::<Vec<_>>();
+            paths.sort(); // read_dir order is unspecified, keep renders reproducible
+            paths
+                .into_iter()
+                .map(|p| Ok(wav::read_file(p)?.convert(sample_rate, channels)))
+                .collect()
+        };
+
+        let pack = Self {
+            clicks: load("clicks")?,
+            releases: load("releases")?,
+            soft_clicks: load("softclicks")?,
+            hard_clicks: load("hardclicks")?,
+        };
+        if pack.clicks.is_empty() {
+            return Err(ClickbotError::NoClicks);
+        }
+        Ok(pack)
+    }
+
+    /// Returns the samples for the given click type, falling back to regular clicks.
+    pub fn samples(&self, typ: ClickType) -> &[AudioBuffer] {
+        let samples = match typ {
+            ClickType::Click => &self.clicks,
+            ClickType::Release => return &self.releases,
+            ClickType::SoftClick => &self.soft_clicks,
+            ClickType::HardClick => &self.hard_clicks,
+        };
+        if samples.is_empty() {
+            &self.clicks
+        } else {
+            samples
+        }
+    }
+}
+
+/// Background song mixed under the clicks.
+#[derive(Debug, Clone)]
+pub struct Song {
+    /// Decoded song audio.
+    pub audio: AudioBuffer,
+    /// Offset into the song (in seconds) that corresponds to the level start.
+    pub offset: f64,
+    /// Song volume multiplier.
+    pub volume: f32,
+}
+
+/// Clickbot render settings.
+#[derive(Debug, Clone)]
+pub struct ClickbotSettings {
+    /// Output sample rate.
+    pub sample_rate: u32,
+    /// Output channel count.
+    pub channels: u16,
+    /// Output sample encoding.
+    pub format: SampleFormat,
+    /// Base volume of the clicks.
+    pub volume: f32,
+    /// Random volume variation, e.g. `0.1` means ±10%.
+    pub volume_variation: f32,
+    /// Clicks closer than this (in seconds) to the previous click are soft clicks.
+    pub soft_threshold: f64,
+    /// Clicks further than this (in seconds) from the previous click are hard clicks.
+    pub hard_threshold: f64,
+    /// Seed for sample selection and volume variation.
+    pub seed: u64,
+    /// Optional song to mix in.
+    pub song: Option<Song>,
+}
+
+impl Default for ClickbotSettings {
+    fn default() -> Self {
+        Self {
+            sample_rate: 44100,
+            channels: 2,
+            format: SampleFormat::Int16,
+            volume: 1.0,
+            volume_variation: 0.1,
+            soft_threshold: 0.1,
+            hard_threshold: 2.0,
+            seed: 0x9E3779B97F4A7C15,
+            song: None,
+        }
+    }
+}
+
+/// Errors that can happen while rendering clicks.
+#[derive(Debug)]
+pub enum ClickbotError {
+    /// Failed to read a sample or write the output.
+    Wav(WavError),
+    /// The clickpack has no regular click samples.
+    NoClicks,
+}
+
+impl std::fmt::Display for ClickbotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Wav(e) => write!(f, "{e}"),
+            Self::NoClicks => write!(f, "clickpack has no click samples"),
+        }
+    }
+}
+
+impl std::error::Error for ClickbotError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Wav(e) => Some(e),
+            Self::NoClicks => None,
+        }
+    }
+}
+
+impl From<WavError> for ClickbotError {
+    fn from(value: WavError) -> Self {
+        Self::Wav(value)
+    }
+}
+
+/// Small xorshift64* generator, so renders are reproducible for a given seed.
+#[derive(Debug, Clone)]
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(if seed == 0 { 0x9E3779B97F4A7C15 } else { seed })
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+
+    /// Returns a float in `[0.0, 1.0)`.
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+/// Picks the click type for a press, given the time since the previous press.
+pub fn click_type(since_last: Option<f64>, settings: &ClickbotSettings) -> ClickType {
+    match since_last {
+        Some(dt) if dt < settings.soft_threshold => ClickType::SoftClick,
+        Some(dt) if dt > settings.hard_threshold => ClickType::HardClick,
+        Some(_) => ClickType::Click,
+        None => ClickType::HardClick, // the first click always comes after a pause
+    }
+}
+
+/// Renders the clicks for `actions` into an audio buffer.
+///
+/// `pack` must already be converted to the settings' sample rate and channel count,
+/// which [`ClickPack::from_dir`] does.
+pub fn render(
+    actions: &[ClickAction],
+    pack: &ClickPack,
+    settings: &ClickbotSettings,
+) -> AudioBuffer {
+    let rate = settings.sample_rate as f64;
+    let channels = settings.channels as usize;
+    let mut rng = Rng::new(settings.seed);
+
+    let mut actions = actions.to_vec();
+    actions.sort_by(|a, b| a.time.total_cmp(&b.time));
+
+    // figure out which sample plays where first, so the output can be allocated once
+    let mut last_press = [None::<f64>; 2];
+    let mut placed = Vec::with_capacity(actions.len());
+    for action in &actions {
+        let player = action.player2 as usize;
+        let typ = if action.down {
+            let typ = click_type(last_press[player].map(|t| action.time - t), settings);
+            last_press[player] = Some(action.time);
+            typ
+        } else {
+            ClickType::Release
+        };
+
+        let samples = pack.samples(typ);
+        if samples.is_empty() {
+            continue;
+        }
+        let sample = &samples[rng.below(samples.len())];
+        let variation = (rng.next_f32() * 2.0 - 1.0) * settings.volume_variation;
+        let volume = settings.volume * (1.0 + variation);
+        let start = (action.time.max(0.0) * rate).round() as usize;
+        placed.push((start, sample, volume));
+    }
+
+    let mut frames = placed
+        .iter()
+        .map(|(start, sample, _)| start + sample.frames())
+        .max()
+        .unwrap_or(0);
+    let song = settings
+        .song
+        .as_ref()
+        .map(|s| (s.audio.convert(settings.sample_rate, settings.channels), s));
+    if let Some((audio, song)) = &song {
+        let skip = (song.offset.max(0.0) * rate).round() as usize;
+        frames = frames.max(audio.frames().saturating_sub(skip));
+    }
+
+    let mut out = vec![0.0f32; frames * channels];
+    if let Some((audio, song)) = &song {
+        let skip = (song.offset.max(0.0) * rate).round() as usize * channels;
+        for (dst, src) in out.iter_mut().zip(audio.samples.iter().skip(skip)) {
+            *dst += src * song.volume;
+        }
+    }
+    for (start, sample, volume) in placed {
+        let dst = &mut out[start * channels..];
+        for (d, s) in dst.iter_mut().zip(&sample.samples) {
+            *d += s * volume;
+        }
+    }
+
+    AudioBuffer {
+        sample_rate: settings.sample_rate,
+        channels: settings.channels,
+        samples: out,
+    }
+}
+
+/// Renders the clicks for `actions` and writes them to a WAV file.
+pub fn render_to_file<P: AsRef<Path>>(
+    path: P,
+    actions: &[ClickAction],
+    pack: &ClickPack,
+    settings: &ClickbotSettings,
+) -> Result<(), ClickbotError> {
+    let audio = render(actions, pack, settings);
+    let mut writer = WavWriter::create(path, audio.sample_rate, audio.channels, settings.format)?;
+    writer.write_samples(&audio.samples)?;
+    writer.finalize()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(value: f32, frames: usize) -> AudioBuffer {
+        AudioBuffer {
+            sample_rate: 10,
+            channels: 1,
+            samples: vec![value; frames],
+        }
+    }
+
+    fn settings() -> ClickbotSettings {
+        ClickbotSettings {
+            sample_rate: 10,
+            channels: 1,
+            volume_variation: 0.0,
+            ..Default::default()
+        }
+    }
+
+    fn press(time: f64) -> ClickAction {
+        ClickAction {
+            time,
+            down: true,
+            player2: false,
+        }
+    }
+
+    #[test]
+    fn click_types() {
+        let settings = settings();
+        assert_eq!(click_type(None, &settings), ClickType::HardClick);
+        assert_eq!(click_type(Some(0.05), &settings), ClickType::SoftClick);
+        assert_eq!(click_type(Some(0.5), &settings), ClickType::Click);
+        assert_eq!(click_type(Some(3.0), &settings), ClickType::HardClick);
+
+        let pack = ClickPack {
+            clicks: vec![sample(1.0, 1)],
+            hard_clicks: vec![sample(2.0, 1)],
+            ..Default::default()
+        };
+        assert_eq!(pack.samples(ClickType::HardClick)[0].samples, [2.0]);
+        // missing groups fall back to clicks, except releases
+        assert_eq!(pack.samples(ClickType::SoftClick)[0].samples, [1.0]);
+        assert!(pack.samples(ClickType::Release).is_empty());
+    }
+
+    #[test]
+    fn mixes_clicks() {
+        let pack = ClickPack {
+            clicks: vec![sample(0.25, 3)],
+            releases: vec![sample(-0.5, 1)],
+            hard_clicks: vec![sample(0.5, 2)],
+            ..Default::default()
+        };
+        let actions = [
+            // unsorted on purpose
+            ClickAction {
+                time: 0.4,
+                down: false,
+                player2: false,
+            },
+            press(0.0),
+            press(0.3),
+            // player 2 has their own first (hard) click
+            ClickAction {
+                player2: true,
+                ..press(0.1)
+            },
+        ];
+        let audio = render(&actions, &pack, &settings());
+        assert_eq!((audio.sample_rate, audio.channels), (10, 1));
+        assert_eq!(audio.samples, [0.5, 1.0, 0.5, 0.25, -0.25, 0.25]);
+    }
+
+    #[test]
+    fn mixes_song() {
+        let pack = ClickPack {
+            clicks: vec![sample(0.5, 1)],
+            ..Default::default()
+        };
+        let settings = ClickbotSettings {
+            song: Some(Song {
+                audio: AudioBuffer {
+                    sample_rate: 10,
+                    channels: 1,
+                    samples: vec![0.1, 0.2, 0.3, 0.4],
+                },
+                offset: 0.1,
+                volume: 0.5,
+            }),
+            ..settings()
+        };
+        let audio = render(&[press(0.1)], &pack, &settings);
+        // the song starts at its offset and makes the output longer than the clicks
+        let expected = [0.1, 0.65, 0.2];
+        assert_eq!(audio.samples.len(), expected.len());
+        for (a, b) in audio.samples.iter().zip(expected) {
+            assert!((a - b).abs() < 1e-6, "{a} != {b}");
+        }
+    }
+
+    #[test]
+    fn volume_variation_is_seeded() {
+        let pack = ClickPack {
+            clicks: vec![sample(1.0, 1)],
+            ..Default::default()
+        };
+        let settings = ClickbotSettings {
+            volume_variation: 0.1,
+            ..settings()
+        };
+        let actions: Vec<_> = (0..20).map(|i| press(i as f64)).collect();
+        let audio = render(&actions, &pack, &settings);
+        assert_eq!(audio, render(&actions, &pack, &settings));
+        let clicks: Vec<_> = audio.samples.iter().step_by(10).collect();
+        assert!(clicks.iter().all(|&&v| (0.9..=1.1).contains(&v)));
+        assert!(clicks.windows(2).any(|w| w[0] != w[1]));
+    }
+}
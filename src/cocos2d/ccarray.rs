@@ -0,0 +1,303 @@
+// struct ccArray { unsigned int num, max; CCObject** arr; }
+// ccArray* CCArray.data = CCArray + 0x20
+
+use super::{get_hmod, make_cocos_wrapper};
+use crate::{impl_addr_funcs, read_ptr, Ptr};
+use std::marker::PhantomData;
+use windows::Win32::Foundation::FARPROC;
+
+/// Dynamic array of `CCObject`s.
+///
+/// Elements are plain addresses, use [`CCArray::iter_as`] or [`CCArray::get_as`]
+/// to read them as a wrapper type (this doesn't check the type of the objects):
+///
+/// ```no_run
+/// # fn objects(array: geometrydash::cocos2d::CCArray) {
+/// use geometrydash::GameObject;
+///
+/// for object in array.iter_as::<GameObject>() {
+///     // ...
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct CCArray {
+    address: Ptr,
+}
+
+impl CCArray {
+    pub const fn from_address(address: Ptr) -> Self {
+        Self { address }
+    }
+
+    /// Creates a new, empty autoreleased array.
+    pub fn create() -> Self {
+        unsafe {
+            let address = (std::mem::transmute::<FARPROC, unsafe extern "cdecl" fn() -> Ptr>(
+                windows::Win32::System::LibraryLoader::GetProcAddress(
+                    get_hmod(),
+                    windows::core::s!("?create@CCArray@cocos2d@@SAPAV12@XZ"),
+                ),
+            ))();
+            Self { address }
+        }
+    }
+
+    make_cocos_wrapper!(
+        "?count@CCArray@cocos2d@@QBEIXZ",
+        "Returns the number of objects in the array.",
+        pub count() -> u32
+    );
+    make_cocos_wrapper!(
+        "?objectAtIndex@CCArray@cocos2d@@QAEPAVCCObject@2@I@Z",
+        "Returns the address of the object at `index`. The index must be in bounds.",
+        pub object_at(index: u32) -> Ptr
+    );
+    make_cocos_wrapper!(
+        "?addObject@CCArray@cocos2d@@QAEXPAVCCObject@2@@Z",
+        "CCArray::addObject",
+        add_object_raw(object: Ptr)
+    );
+    make_cocos_wrapper!(
+        "?removeObject@CCArray@cocos2d@@QAEXPAVCCObject@2@_N@Z",
+        "CCArray::removeObject",
+        remove_object_raw(object: Ptr, release: bool)
+    );
+    make_cocos_wrapper!(
+        "?removeObjectAtIndex@CCArray@cocos2d@@QAEXI_N@Z",
+        "Removes the object at `index`, releasing it. The index must be in bounds.",
+        pub remove_object_at(index: u32, release: bool)
+    );
+    make_cocos_wrapper!(
+        "?removeAllObjects@CCArray@cocos2d@@QAEXXZ",
+        "Removes (and releases) every object.",
+        pub remove_all_objects()
+    );
+    make_cocos_wrapper!(
+        "?containsObject@CCArray@cocos2d@@QBE_NPAVCCObject@2@@Z",
+        "CCArray::containsObject",
+        contains_object_raw(object: Ptr) -> bool
+    );
+
+    /// Appends an object to the array, retaining it.
+    #[inline]
+    pub fn add_object<T: Into<Ptr>>(&self, object: T) {
+        self.add_object_raw(object.into())
+    }
+
+    /// Removes the first occurrence of an object, releasing it.
+    #[inline]
+    pub fn remove_object<T: Into<Ptr>>(&self, object: T) {
+        self.remove_object_raw(object.into(), true)
+    }
+
+    /// Returns whether the array contains an object.
+    #[inline]
+    pub fn contains<T: Into<Ptr>>(&self, object: T) -> bool {
+        self.contains_object_raw(object.into())
+    }
+
+    /// Returns the backing storage of the array.
+    fn raw(&self) -> (*const Ptr, usize) {
+        if self.address == 0 {
+            return (std::ptr::null(), 0);
+        }
+        unsafe {
+            let data = read_ptr(self.address + 0x20);
+            if data == 0 {
+                return (std::ptr::null(), 0);
+            }
+            let num = *(data as *const u32) as usize;
+            (read_ptr(data + 8) as *const Ptr, num)
+        }
+    }
+
+    /// Returns the number of objects in the array, 0 for a null array.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.raw().1
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the objects in the array.
+    ///
+    /// # Safety
+    ///
+    /// The slice points into the game's memory: the array must not be changed
+    /// or freed while it's alive, e.g. by calling into the game or running on
+    /// a thread other than the main thread. [`CCArray::get`] and
+    /// [`CCArray::iter`] copy the addresses instead.
+    #[inline]
+    pub unsafe fn as_slice(&self) -> &[Ptr] {
+        match self.raw() {
+            (ptr, len) if !ptr.is_null() => std::slice::from_raw_parts(ptr, len),
+            _ => &[],
+        }
+    }
+
+    /// Returns the address of the object at `index`.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Ptr> {
+        let (ptr, len) = self.raw();
+        (index < len).then(|| unsafe { *ptr.add(index) })
+    }
+
+    /// Returns the object at `index` as a `T`.
+    #[inline]
+    pub fn get_as<T: From<Ptr>>(&self, index: usize) -> Option<T> {
+        self.get(index).map(T::from)
+    }
+
+    /// Iterates over the addresses of the objects.
+    #[inline]
+    pub fn iter(&self) -> CCArrayIter<Ptr> {
+        self.iter_as()
+    }
+
+    /// Iterates over the objects as `T`s. A null array is empty.
+    #[inline]
+    pub fn iter_as<T: From<Ptr>>(&self) -> CCArrayIter<T> {
+        CCArrayIter {
+            array: *self,
+            front: 0,
+            back: self.len(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl IntoIterator for &CCArray {
+    type Item = Ptr;
+    type IntoIter = CCArrayIter<Ptr>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl_addr_funcs!(CCArray);
+
+/// Iterator over the objects of a [`CCArray`].
+///
+/// Every object is read when it's reached, the iterator ends early if the
+/// array shrinks in the meantime.
+#[derive(Debug, Clone)]
+pub struct CCArrayIter<T> {
+    array: CCArray,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T: From<Ptr>> Iterator for CCArrayIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.front >= self.back {
+            return None;
+        }
+        let Some(object) = self.array.get(self.front) else {
+            self.front = self.back;
+            return None;
+        };
+        self.front += 1;
+        Some(T::from(object))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.back - self.front))
+    }
+}
+
+impl<T: From<Ptr>> DoubleEndedIterator for CCArrayIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        let Some(object) = self.array.get(self.back) else {
+            self.back = self.front;
+            return None;
+        };
+        Some(T::from(object))
+    }
+}
+
+impl<T: From<Ptr>> std::iter::FusedIterator for CCArrayIter<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The parts of a `CCArray` that are read directly.
+    #[repr(C)]
+    struct FakeArray {
+        _object: [u8; 0x20],
+        data: *mut RawArray,
+    }
+
+    #[repr(C)]
+    struct RawArray {
+        num: u32,
+        max: u32,
+        arr: *const Ptr,
+    }
+
+    #[test]
+    fn reads_objects() {
+        let objects: [Ptr; 3] = [0x10, 0x20, 0x30];
+        let mut raw = RawArray {
+            num: 3,
+            max: 4,
+            arr: objects.as_ptr(),
+        };
+        let raw_ptr = std::ptr::addr_of_mut!(raw);
+        let fake = FakeArray {
+            _object: [0; 0x20],
+            data: raw_ptr,
+        };
+        let array = CCArray::from_address(std::ptr::addr_of!(fake) as Ptr);
+
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.get(1), Some(0x20));
+        assert_eq!(array.get(3), None);
+        assert_eq!(array.get_as::<CCArray>(2), Some(CCArray::from_address(0x30)));
+        assert_eq!(array.iter().collect::<Vec<_>>(), objects);
+        assert_eq!(array.iter().rev().collect::<Vec<_>>(), [0x30, 0x20, 0x10]);
+        assert_eq!(unsafe { array.as_slice() }, objects);
+
+        // the array shrinking ends the iterators
+        let mut iter = array.iter();
+        let mut back = array.iter();
+        assert_eq!(iter.next(), Some(0x10));
+        unsafe { (*raw_ptr).num = 1 };
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(back.next_back(), None);
+        assert_eq!(array.len(), 1);
+    }
+
+    #[test]
+    fn null_arrays_are_empty() {
+        let null = CCArray::from_address(0);
+        assert!(null.is_empty());
+        assert_eq!(null.get(0), None);
+        assert_eq!(null.iter().count(), 0);
+
+        let fake = FakeArray {
+            _object: [0; 0x20],
+            data: std::ptr::null_mut(),
+        };
+        let array = CCArray::from_address(std::ptr::addr_of!(fake) as Ptr);
+        assert!(array.is_empty());
+        assert_eq!(unsafe { array.as_slice() }, &[] as &[Ptr]);
+    }
+}
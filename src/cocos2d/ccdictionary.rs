@@ -0,0 +1,306 @@
+// struct CCDictElement {
+//     char m_szKey[257];        // 0x0
+//     intptr_t m_iKey;          // 0x104
+//     CCObject* m_pObject;      // 0x108
+//     UT_hash_handle hh;        // 0x10C, hh.next = 0x114
+// }
+// CCDictElement* CCDictionary.m_pElements = CCDictionary + 0x20
+// CCDictType CCDictionary.m_eDictType = CCDictionary + 0x24
+
+use super::make_cocos_wrapper;
+use crate::{impl_addr_funcs, read_mem, read_ptr, Ptr};
+use std::{ffi::CStr, marker::PhantomData};
+
+/// Key of a [`CCDictionary`] entry.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DictKey {
+    Int(isize),
+    Str(String),
+}
+
+/// Hash map from integer or string keys to `CCObject`s.
+///
+/// A dictionary only uses one kind of key, decided by the first insertion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct CCDictionary {
+    address: Ptr,
+}
+
+impl CCDictionary {
+    pub const fn from_address(address: Ptr) -> Self {
+        Self { address }
+    }
+
+    make_cocos_wrapper!(
+        "?count@CCDictionary@cocos2d@@QAEIXZ",
+        "Returns the number of entries.",
+        pub count() -> u32
+    );
+    make_cocos_wrapper!(
+        "?objectForKey@CCDictionary@cocos2d@@QAEPAVCCObject@2@H@Z",
+        "CCDictionary::objectForKey",
+        object_for_key_raw(key: isize) -> Ptr
+    );
+    make_cocos_wrapper!(
+        "?setObject@CCDictionary@cocos2d@@QAEXPAVCCObject@2@H@Z",
+        "CCDictionary::setObject",
+        set_object_raw(object: Ptr, key: isize)
+    );
+    make_cocos_wrapper!(
+        "?removeObjectForKey@CCDictionary@cocos2d@@QAEXH@Z",
+        "CCDictionary::removeObjectForKey",
+        remove_object_for_key_raw(key: isize)
+    );
+    make_cocos_wrapper!(
+        "?removeAllObjects@CCDictionary@cocos2d@@QAEXXZ",
+        "Removes (and releases) every object.",
+        pub remove_all_objects()
+    );
+
+    /// Returns the number of entries. A null dictionary is empty.
+    #[inline]
+    pub fn len(&self) -> usize {
+        if self.address == 0 {
+            return 0;
+        }
+        self.count() as usize
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.elements().next == 0
+    }
+
+    /// Returns whether the keys are strings. Empty dictionaries have no key type yet.
+    #[inline]
+    pub fn has_string_keys(&self) -> bool {
+        self.address != 0 && unsafe { *read_mem::<i32>(self.address + 0x24) == 1 }
+    }
+
+    /// Returns the object for an integer key.
+    pub fn get(&self, key: isize) -> Option<Ptr> {
+        if self.is_empty() || self.has_string_keys() {
+            return None;
+        }
+        Some(self.object_for_key_raw(key)).filter(|&p| p != 0)
+    }
+
+    /// Returns the object for a string key.
+    pub fn get_str(&self, key: &str) -> Option<Ptr> {
+        if !self.has_string_keys() {
+            return None;
+        }
+        self.elements()
+            .find(|&e| unsafe { element_key_str(e) }.to_bytes() == key.as_bytes())
+            .map(|e| unsafe { read_ptr(e + 0x108) })
+    }
+
+    /// Returns the object for an integer key as a `T`.
+    #[inline]
+    pub fn get_as<T: From<Ptr>>(&self, key: isize) -> Option<T> {
+        self.get(key).map(T::from)
+    }
+
+    /// Inserts an object for an integer key, retaining it and releasing the old one.
+    ///
+    /// Returns `false` without inserting into a null or string-keyed dictionary.
+    pub fn insert<T: Into<Ptr>>(&self, key: isize, object: T) -> bool {
+        // the key type isn't checked in release builds of cocos, the hash would break
+        if self.address == 0 || self.has_string_keys() {
+            return false;
+        }
+        self.set_object_raw(object.into(), key);
+        true
+    }
+
+    /// Removes (and releases) the object for an integer key.
+    ///
+    /// Returns `false` without removing anything from a null or string-keyed dictionary.
+    pub fn remove_object_for_key(&self, key: isize) -> bool {
+        if self.address == 0 || self.has_string_keys() {
+            return false;
+        }
+        self.remove_object_for_key_raw(key);
+        true
+    }
+
+    fn elements(&self) -> Elements {
+        let next = if self.address == 0 {
+            0
+        } else {
+            unsafe { read_ptr(self.address + 0x20) }
+        };
+        Elements { next }
+    }
+
+    /// Iterates over the entries. A null dictionary is empty.
+    #[inline]
+    pub fn iter(&self) -> CCDictionaryIter<Ptr> {
+        self.iter_as()
+    }
+
+    /// Iterates over the entries, reading the objects as `T`s.
+    pub fn iter_as<T: From<Ptr>>(&self) -> CCDictionaryIter<T> {
+        CCDictionaryIter {
+            elements: self.elements(),
+            string_keys: self.has_string_keys(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Iterates over the objects as `T`s.
+    pub fn values_as<T: From<Ptr>>(&self) -> impl Iterator<Item = T> {
+        self.elements()
+            .map(|e| T::from(unsafe { read_ptr(e + 0x108) }))
+    }
+}
+
+impl_addr_funcs!(CCDictionary);
+
+unsafe fn element_key_str<'a>(element: Ptr) -> &'a CStr {
+    CStr::from_ptr(element as *const std::ffi::c_char)
+}
+
+/// Walks the linked list of `CCDictElement`s.
+#[derive(Debug, Clone)]
+struct Elements {
+    next: Ptr,
+}
+
+impl Iterator for Elements {
+    type Item = Ptr;
+
+    fn next(&mut self) -> Option<Ptr> {
+        let element = self.next;
+        if element == 0 {
+            return None;
+        }
+        self.next = unsafe { read_ptr(element + 0x114) };
+        Some(element)
+    }
+}
+
+/// Iterator over the entries of a [`CCDictionary`].
+#[derive(Debug, Clone)]
+pub struct CCDictionaryIter<T> {
+    elements: Elements,
+    string_keys: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T: From<Ptr>> Iterator for CCDictionaryIter<T> {
+    type Item = (DictKey, T);
+
+    fn next(&mut self) -> Option<(DictKey, T)> {
+        let element = self.elements.next()?;
+        unsafe {
+            let key = if self.string_keys {
+                DictKey::Str(element_key_str(element).to_string_lossy().into_owned())
+            } else {
+                DictKey::Int(*read_mem::<isize>(element + 0x104))
+            };
+            Some((key, T::from(read_ptr(element + 0x108))))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the fakes have the layout of the 32-bit game, with 64-bit pointers the
+    // fields overlap
+
+    /// The parts of a `CCDictionary` that are read directly.
+    #[cfg(target_pointer_width = "32")]
+    #[repr(C)]
+    struct FakeDictionary {
+        _object: [u8; 0x20],
+        elements: *const FakeElement,
+        dict_type: i32,
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[repr(C)]
+    struct FakeElement {
+        key: [u8; 0x104],
+        int_key: isize,
+        object: Ptr,
+        _hh: [u8; 8],
+        next: *const FakeElement,
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    fn element(key: &str, int_key: isize, object: Ptr, next: *const FakeElement) -> FakeElement {
+        let mut bytes = [0; 0x104];
+        bytes[..key.len()].copy_from_slice(key.as_bytes());
+        FakeElement {
+            key: bytes,
+            int_key,
+            object,
+            _hh: [0; 8],
+            next,
+        }
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "32")]
+    fn reads_objects() {
+        let second = element("b", 0, 0x20, std::ptr::null());
+        let first = element("a", 0, 0x10, &second);
+        let fake = FakeDictionary {
+            _object: [0; 0x20],
+            elements: &first,
+            dict_type: 1,
+        };
+        let dict = CCDictionary::from_address(std::ptr::addr_of!(fake) as Ptr);
+
+        assert!(!dict.is_empty());
+        assert!(dict.has_string_keys());
+        assert_eq!(dict.get_str("b"), Some(0x20));
+        assert_eq!(dict.get_str("c"), None);
+        // integer keys don't go through to the game on string-keyed dictionaries
+        assert_eq!(dict.get(1), None);
+        assert!(!dict.insert(1, 0x30 as Ptr));
+        assert!(!dict.remove_object_for_key(1));
+        assert_eq!(
+            dict.iter().collect::<Vec<_>>(),
+            [
+                (DictKey::Str("a".to_string()), 0x10),
+                (DictKey::Str("b".to_string()), 0x20)
+            ]
+        );
+
+        let second = element("", 7, 0x20, std::ptr::null());
+        let first = element("", 3, 0x10, &second);
+        let fake = FakeDictionary {
+            _object: [0; 0x20],
+            elements: &first,
+            dict_type: 0,
+        };
+        let dict = CCDictionary::from_address(std::ptr::addr_of!(fake) as Ptr);
+        assert!(!dict.has_string_keys());
+        assert_eq!(dict.get_str("a"), None);
+        assert_eq!(
+            dict.iter_as::<CCDictionary>()
+                .map(|(k, _)| k)
+                .collect::<Vec<_>>(),
+            [DictKey::Int(3), DictKey::Int(7)]
+        );
+        assert_eq!(dict.values_as::<Ptr>().collect::<Vec<_>>(), [0x10, 0x20]);
+    }
+
+    #[test]
+    fn null_dictionaries_are_empty() {
+        let null = CCDictionary::from_address(0);
+        assert!(null.is_empty());
+        assert_eq!(null.len(), 0);
+        assert!(!null.has_string_keys());
+        assert_eq!(null.get(1), None);
+        assert_eq!(null.get_str("a"), None);
+        assert!(!null.insert(1, 0x10 as Ptr));
+        assert!(!null.remove_object_for_key(1));
+        assert_eq!(null.iter().count(), 0);
+    }
+}
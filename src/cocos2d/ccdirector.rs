@@ -1,4 +1,7 @@
-use super::{get_hmod, CCScheduler};
+use super::{
+    get_hmod, make_cocos_wrapper, CCKeyboardDispatcher, CCNode, CCScheduler, CCSize,
+    CCTouchDispatcher,
+};
 use crate::{impl_addr_funcs, Ptr};
 use windows::Win32::Foundation::FARPROC;
 
@@ -59,9 +62,67 @@ impl CCDirector {
         }
     }
 
+    make_cocos_wrapper!(
+        "?getRunningScene@CCDirector@cocos2d@@QAEPAVCCScene@2@XZ",
+        "CCDirector::getRunningScene",
+        get_running_scene_raw() -> Ptr
+    );
+
+    /// Returns the scene that is currently being shown.
+    ///
+    /// During a transition this is the transition scene, the new scene is one of its children.
+    #[inline]
+    pub fn running_scene(&self) -> CCNode {
+        CCNode::from_address(self.get_running_scene_raw())
+    }
+
+    make_cocos_wrapper!(
+        "?getTotalFrames@CCDirector@cocos2d@@QAEIXZ",
+        "Returns the number of frames drawn since the game started.",
+        pub total_frames() -> u32
+    );
+
+    // CCSize is returned through a hidden pointer
+    make_cocos_wrapper!(
+        "?getWinSize@CCDirector@cocos2d@@QAE?AVCCSize@2@XZ",
+        "CCDirector::getWinSize",
+        get_win_size_raw(out: *mut CCSize) -> *mut CCSize
+    );
+
+    /// Returns the size of the window in points, the coordinate space of the scenes.
+    #[inline]
+    pub fn win_size(&self) -> CCSize {
+        let mut size = CCSize::default();
+        self.get_win_size_raw(&mut size);
+        size
+    }
+
+    make_cocos_wrapper!(
+        "?getKeyboardDispatcher@CCDirector@cocos2d@@QAEPAVCCKeyboardDispatcher@2@XZ",
+        "CCDirector::getKeyboardDispatcher",
+        get_keyboard_dispatcher_raw() -> Ptr
+    );
+    make_cocos_wrapper!(
+        "?getTouchDispatcher@CCDirector@cocos2d@@QAEPAVCCTouchDispatcher@2@XZ",
+        "CCDirector::getTouchDispatcher",
+        get_touch_dispatcher_raw() -> Ptr
+    );
+
+    #[inline]
+    pub fn keyboard_dispatcher(&self) -> CCKeyboardDispatcher {
+        CCKeyboardDispatcher::from_address(self.get_keyboard_dispatcher_raw())
+    }
+
+    #[inline]
+    pub fn touch_dispatcher(&self) -> CCTouchDispatcher {
+        CCTouchDispatcher::from_address(self.get_touch_dispatcher_raw())
+    }
+
+    /// Returns the scheduler that runs the update functions and actions.
     #[inline]
     pub fn scheduler(&self) -> CCScheduler {
-        CCScheduler::from_address(self.address + 0x48)
+        // CCScheduler* CCDirector.scheduler = CCDirector + 0x48
+        CCScheduler::from_address(unsafe { crate::read_ptr(self.address + 0x48) })
     }
 }
 
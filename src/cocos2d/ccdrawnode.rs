@@ -0,0 +1,176 @@
+use super::{get_hmod, make_cocos_wrapper, CCNode, CCPoint};
+use crate::{
+    hitbox::{Color, Geometry, HitboxSink, Shape},
+    impl_addr_funcs, PlayLayer, Ptr,
+};
+use windows::Win32::Foundation::FARPROC;
+
+/// A node that draws dots, segments and polygons (`cocos2d::CCDrawNode`).
+///
+/// Shapes stay until [`CCDrawNode::clear`] is called, so overlays usually clear
+/// and redraw the node every frame. Add it to [`PlayLayer::object_layer`] with
+/// [`CCDrawNode::attach_to_level`] to draw in level coordinates:
+///
+/// ```no_run
+/// # fn draw(play_layer: geometrydash::PlayLayer) {
+/// use geometrydash::{cocos2d::CCDrawNode, hitbox::Color};
+///
+/// let node = CCDrawNode::create();
+/// node.attach_to_level(play_layer, 1000);
+/// node.draw_segment((0.0, 105.0), (300.0, 105.0), 1.0, Color::new(1.0, 1.0, 1.0, 1.0));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct CCDrawNode {
+    address: Ptr,
+}
+
+impl CCDrawNode {
+    /// Number of segments circles are drawn with.
+    pub const CIRCLE_SEGMENTS: usize = 32;
+
+    pub const fn from_address(address: Ptr) -> Self {
+        Self { address }
+    }
+
+    /// Creates a new, empty autoreleased draw node.
+    pub fn create() -> Self {
+        unsafe {
+            let address = (std::mem::transmute::<FARPROC, unsafe extern "cdecl" fn() -> Ptr>(
+                windows::Win32::System::LibraryLoader::GetProcAddress(
+                    get_hmod(),
+                    windows::core::s!("?create@CCDrawNode@cocos2d@@SAPAV12@XZ"),
+                ),
+            ))();
+            Self::from_address(address)
+        }
+    }
+
+    make_cocos_wrapper!(
+        "?drawDot@CCDrawNode@cocos2d@@QAE_NABVCCPoint@2@MABU_ccColor4F@2@@Z",
+        "CCDrawNode::drawDot",
+        draw_dot_raw(position: *const CCPoint, radius: f32, color: *const Color) -> bool
+    );
+    make_cocos_wrapper!(
+        "?drawSegment@CCDrawNode@cocos2d@@QAE_NABVCCPoint@2@0MABU_ccColor4F@2@@Z",
+        "CCDrawNode::drawSegment",
+        draw_segment_raw(from: *const CCPoint, to: *const CCPoint, radius: f32, color: *const Color) -> bool
+    );
+    make_cocos_wrapper!(
+        "?drawPolygon@CCDrawNode@cocos2d@@QAE_NPAVCCPoint@2@IABU_ccColor4F@2@M1@Z",
+        "CCDrawNode::drawPolygon",
+        draw_polygon_raw(
+            vertices: *const CCPoint,
+            count: u32,
+            fill: *const Color,
+            border_width: f32,
+            border: *const Color
+        ) -> bool
+    );
+    make_cocos_wrapper!(
+        "?clear@CCDrawNode@cocos2d@@QAEXXZ",
+        "Removes every shape drawn so far.",
+        pub clear()
+    );
+
+    /// Returns the draw node as a [`CCNode`], to position or add it to a parent.
+    #[inline]
+    pub fn as_node(&self) -> CCNode {
+        CCNode::from_address(self.address)
+    }
+
+    /// Draws a filled circle.
+    pub fn draw_dot<P: Into<CCPoint>>(&self, position: P, radius: f32, color: Color) -> bool {
+        self.draw_dot_raw(&position.into(), radius, &color)
+    }
+
+    /// Draws a segment with round ends, `radius` is half of its width.
+    pub fn draw_segment<P: Into<CCPoint>>(
+        &self,
+        from: P,
+        to: P,
+        radius: f32,
+        color: Color,
+    ) -> bool {
+        self.draw_segment_raw(&from.into(), &to.into(), radius, &color)
+    }
+
+    /// Draws a convex polygon. Use a transparent `fill` or a zero `border_width`
+    /// to only draw the outline or the inside.
+    pub fn draw_polygon<P: Into<CCPoint> + Copy>(
+        &self,
+        vertices: &[P],
+        fill: Color,
+        border_width: f32,
+        border: Color,
+    ) -> bool {
+        let vertices = vertices.iter().map(|&v| v.into()).collect::<Vec<CCPoint>>();
+        self.draw_polygon_raw(
+            vertices.as_ptr(),
+            vertices.len() as u32,
+            &fill,
+            border_width,
+            &border,
+        )
+    }
+
+    /// Draws a circle as a polygon, unlike [`CCDrawNode::draw_dot`] it can have a border.
+    pub fn draw_circle<P: Into<CCPoint>>(
+        &self,
+        center: P,
+        radius: f32,
+        fill: Color,
+        border_width: f32,
+        border: Color,
+    ) -> bool {
+        let center = center.into();
+        let vertices = (0..Self::CIRCLE_SEGMENTS)
+            .map(|i| {
+                let angle = i as f32 / Self::CIRCLE_SEGMENTS as f32 * std::f32::consts::TAU;
+                (
+                    center.x + radius * angle.cos(),
+                    center.y + radius * angle.sin(),
+                )
+            })
+            .collect::<Vec<_>>();
+        self.draw_polygon(&vertices, fill, border_width, border)
+    }
+
+    /// Adds the node to the object layer of the level, so it uses level
+    /// coordinates and moves with the camera.
+    pub fn attach_to_level(&self, play_layer: PlayLayer, z_order: i32) {
+        play_layer
+            .object_layer()
+            .add_child(self.as_node(), z_order, -1);
+    }
+
+    /// Offsets the node by the camera position, for nodes that are added to a
+    /// layer which doesn't move with the camera (e.g. the `PlayLayer` itself).
+    pub fn follow_camera(&self, play_layer: PlayLayer) {
+        self.as_node()
+            .set_position((-play_layer.camera_x(), -play_layer.camera_y()));
+    }
+}
+
+/// Draws hitbox shapes, clearing the node at the start of each frame.
+impl HitboxSink for CCDrawNode {
+    fn begin(&mut self) {
+        self.clear();
+    }
+
+    fn shape(&mut self, shape: &Shape) {
+        const BORDER_WIDTH: f32 = 0.5;
+        match shape.geometry {
+            Geometry::Quad(corners) => {
+                self.draw_polygon(&corners, shape.fill, BORDER_WIDTH, shape.outline);
+            }
+            Geometry::Circle { center, radius } => {
+                self.draw_circle(center, radius, shape.fill, BORDER_WIDTH, shape.outline);
+            }
+        }
+    }
+}
+
+impl_addr_funcs!(CCDrawNode);
+crate::rtti::impl_rtti_class!(CCDrawNode, "cocos2d::CCDrawNode");
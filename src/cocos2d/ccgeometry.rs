@@ -0,0 +1,39 @@
+/// A 2D point (`cocos2d::CCPoint`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct CCPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl CCPoint {
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<(f32, f32)> for CCPoint {
+    fn from(value: (f32, f32)) -> Self {
+        Self::new(value.0, value.1)
+    }
+}
+
+impl From<CCPoint> for (f32, f32) {
+    fn from(value: CCPoint) -> Self {
+        (value.x, value.y)
+    }
+}
+
+/// A 2D size (`cocos2d::CCSize`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct CCSize {
+    pub width: f32,
+    pub height: f32,
+}
+
+impl CCSize {
+    pub const fn new(width: f32, height: f32) -> Self {
+        Self { width, height }
+    }
+}
@@ -0,0 +1,25 @@
+use super::make_cocos_wrapper;
+use crate::{impl_addr_funcs, Ptr};
+
+/// Sends key presses to the keyboard delegates (`cocos2d::CCKeyboardDispatcher`).
+///
+/// Key codes are Windows virtual-key codes, see [`crate::input::KeyCode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct CCKeyboardDispatcher {
+    address: Ptr,
+}
+
+impl CCKeyboardDispatcher {
+    pub const fn from_address(address: Ptr) -> Self {
+        Self { address }
+    }
+
+    make_cocos_wrapper!(
+        "?dispatchKeyboardMSG@CCKeyboardDispatcher@cocos2d@@QAE_NW4enumKeyCodes@2@_N@Z",
+        "Simulates a key press or release. Returns whether a delegate handled it.",
+        pub dispatch_keyboard_msg(key: u32, down: bool) -> bool
+    );
+}
+
+impl_addr_funcs!(CCKeyboardDispatcher);
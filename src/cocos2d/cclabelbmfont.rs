@@ -0,0 +1,117 @@
+use super::{get_hmod, make_cocos_wrapper, CCNode, CCPoint};
+use crate::{impl_addr_funcs, Ptr};
+use std::ffi::CString;
+use windows::Win32::Foundation::FARPROC;
+
+/// An RGB color (`cocos2d::ccColor3B`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(C)]
+pub struct CCColor3B {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl CCColor3B {
+    pub const WHITE: Self = Self::new(255, 255, 255);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+}
+
+/// A text label drawn with a bitmap font (`cocos2d::CCLabelBMFont`).
+///
+/// GD's fonts are `bigFont.fnt`, `chatFont.fnt`, `goldFont.fnt` and
+/// `gjFont01.fnt` to `gjFont11.fnt`.
+///
+/// ```no_run
+/// use geometrydash::cocos2d::{CCDirector, CCLabelBMFont};
+///
+/// let label = CCLabelBMFont::create("Hello", "bigFont.fnt");
+/// label.set_scale(0.5);
+/// CCDirector::shared().running_scene().add_child(label.as_node(), 100, -1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct CCLabelBMFont {
+    address: Ptr,
+}
+
+impl CCLabelBMFont {
+    pub const fn from_address(address: Ptr) -> Self {
+        Self { address }
+    }
+
+    /// Creates an autoreleased label. Returns a null label if the font can't be loaded.
+    pub fn create(text: &str, font_file: &str) -> Self {
+        let text = to_cstring(text);
+        let font_file = to_cstring(font_file);
+        unsafe {
+            let address = (std::mem::transmute::<
+                FARPROC,
+                unsafe extern "cdecl" fn(*const u8, *const u8) -> Ptr,
+            >(windows::Win32::System::LibraryLoader::GetProcAddress(
+                get_hmod(),
+                windows::core::s!("?create@CCLabelBMFont@cocos2d@@SAPAV12@PBD0@Z"),
+            )))(text.as_ptr().cast(), font_file.as_ptr().cast());
+            Self::from_address(address)
+        }
+    }
+
+    make_cocos_wrapper!(
+        "?setString@CCLabelBMFont@cocos2d@@UAEXPBD@Z",
+        "CCLabelBMFont::setString",
+        set_string_raw(text: *const u8)
+    );
+    make_cocos_wrapper!(
+        "?setAnchorPoint@CCLabelBMFont@cocos2d@@UAEXABVCCPoint@2@@Z",
+        "CCLabelBMFont::setAnchorPoint",
+        set_anchor_point_raw(anchor: *const CCPoint)
+    );
+    make_cocos_wrapper!(
+        "?setColor@CCLabelBMFont@cocos2d@@UAEXABU_ccColor3B@2@@Z",
+        "CCLabelBMFont::setColor",
+        set_color_raw(color: *const CCColor3B)
+    );
+    make_cocos_wrapper!(
+        "?setOpacity@CCLabelBMFont@cocos2d@@UAEXE@Z",
+        "Sets the opacity, from 0 (transparent) to 255.",
+        pub set_opacity(opacity: u8)
+    );
+    make_cocos_wrapper!(
+        "?setScale@CCLabelBMFont@cocos2d@@UAEXM@Z",
+        "Sets both scale factors.",
+        pub set_scale(scale: f32)
+    );
+
+    /// Returns the label as a [`CCNode`], to position or add it to a parent.
+    #[inline]
+    pub fn as_node(&self) -> CCNode {
+        CCNode::from_address(self.address)
+    }
+
+    /// Changes the text.
+    pub fn set_string(&self, text: &str) {
+        self.set_string_raw(to_cstring(text).as_ptr().cast())
+    }
+
+    /// Sets the point of the label that is placed at its position, from `(0, 0)`
+    /// (bottom left) to `(1, 1)` (top right).
+    pub fn set_anchor_point<P: Into<CCPoint>>(&self, anchor: P) {
+        self.set_anchor_point_raw(&anchor.into())
+    }
+
+    pub fn set_color(&self, color: CCColor3B) {
+        self.set_color_raw(&color)
+    }
+}
+
+/// Converts a string for cocos2d, cutting it at the first nul.
+fn to_cstring(text: &str) -> CString {
+    let text = text.split('\0').next().unwrap_or_default();
+    CString::new(text).unwrap_or_default()
+}
+
+impl_addr_funcs!(CCLabelBMFont);
+crate::rtti::impl_rtti_class!(CCLabelBMFont, "cocos2d::CCLabelBMFont");
@@ -0,0 +1,278 @@
+use super::{make_cocos_wrapper, make_virtual_wrapper, CCArray, CCPoint, CCSize};
+use crate::{
+    impl_addr_funcs,
+    rtti::{self, impl_rtti_class, RttiClass},
+    AddressUtils, Ptr,
+};
+
+/// Base class of everything that is drawn in cocos2d: scenes, layers, sprites...
+///
+/// Every node has a position, scale, rotation, z-order and tag, and an array of
+/// children which are drawn relative to it.
+///
+/// Use [`CCNode::descendants`] to walk the whole tree, e.g. to look for a layer
+/// in the running scene:
+///
+/// ```no_run
+/// use geometrydash::cocos2d::CCDirector;
+///
+/// let scene = CCDirector::shared().running_scene();
+/// let tagged = scene.descendants().find(|node| node.tag() == 1234);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct CCNode {
+    address: Ptr,
+}
+
+impl CCNode {
+    pub const fn from_address(address: Ptr) -> Self {
+        Self { address }
+    }
+
+    /// Creates an empty autoreleased node.
+    pub fn create() -> Self {
+        unsafe {
+            let address = (std::mem::transmute::<
+                windows::Win32::Foundation::FARPROC,
+                unsafe extern "cdecl" fn() -> Ptr,
+            >(windows::Win32::System::LibraryLoader::GetProcAddress(
+                super::get_hmod(),
+                windows::core::s!("?create@CCNode@cocos2d@@SAPAV12@XZ"),
+            )))();
+            Self::from_address(address)
+        }
+    }
+
+    make_cocos_wrapper!(
+        "?retain@CCObject@cocos2d@@QAEXXZ",
+        "Increments the reference count, so the node outlives the autorelease pool.",
+        pub retain()
+    );
+    make_cocos_wrapper!(
+        "?release@CCObject@cocos2d@@QAEXXZ",
+        "Decrements the reference count, deleting the node when it reaches zero.",
+        pub release()
+    );
+    make_virtual_wrapper!(
+        "?getPosition@CCNode@cocos2d@@UAEABVCCPoint@2@XZ",
+        "CCNode::getPosition",
+        get_position_raw() -> *const CCPoint
+    );
+    make_virtual_wrapper!(
+        "?getContentSize@CCNode@cocos2d@@UBEABVCCSize@2@XZ",
+        "CCNode::getContentSize",
+        get_content_size_raw() -> *const CCSize
+    );
+    make_virtual_wrapper!(
+        "?setPosition@CCNode@cocos2d@@UAEXABVCCPoint@2@@Z",
+        "CCNode::setPosition",
+        set_position_raw(position: *const CCPoint)
+    );
+    make_virtual_wrapper!(
+        "?getParent@CCNode@cocos2d@@UAEPAV12@XZ",
+        "CCNode::getParent",
+        get_parent_raw() -> Ptr
+    );
+    make_virtual_wrapper!(
+        "?getChildren@CCNode@cocos2d@@UAEPAVCCArray@2@XZ",
+        "CCNode::getChildren",
+        get_children_raw() -> Ptr
+    );
+    make_virtual_wrapper!(
+        "?getChildByTag@CCNode@cocos2d@@UAEPAV12@H@Z",
+        "CCNode::getChildByTag",
+        get_child_by_tag_raw(tag: i32) -> Ptr
+    );
+
+    /// Returns the position of the node, relative to its parent.
+    #[inline]
+    pub fn position(&self) -> CCPoint {
+        unsafe { *self.get_position_raw() }
+    }
+
+    /// Sets the position of the node, relative to its parent.
+    #[inline]
+    pub fn set_position<P: Into<CCPoint>>(&self, position: P) {
+        let position = position.into();
+        self.set_position_raw(&position)
+    }
+
+    /// Returns the untransformed size of the node.
+    #[inline]
+    pub fn content_size(&self) -> CCSize {
+        unsafe { *self.get_content_size_raw() }
+    }
+
+    make_virtual_wrapper!(
+        "?getScaleX@CCNode@cocos2d@@UAEMXZ",
+        "Returns the horizontal scale factor.",
+        pub scale_x() -> f32
+    );
+    make_virtual_wrapper!(
+        "?getScaleY@CCNode@cocos2d@@UAEMXZ",
+        "Returns the vertical scale factor.",
+        pub scale_y() -> f32
+    );
+    make_virtual_wrapper!(
+        "?setScaleX@CCNode@cocos2d@@UAEXM@Z",
+        "Sets the horizontal scale factor.",
+        pub set_scale_x(scale: f32)
+    );
+    make_virtual_wrapper!(
+        "?setScaleY@CCNode@cocos2d@@UAEXM@Z",
+        "Sets the vertical scale factor.",
+        pub set_scale_y(scale: f32)
+    );
+    make_virtual_wrapper!(
+        "?setScale@CCNode@cocos2d@@UAEXM@Z",
+        "Sets both scale factors.",
+        pub set_scale(scale: f32)
+    );
+    make_virtual_wrapper!(
+        "?getRotation@CCNode@cocos2d@@UAEMXZ",
+        "Returns the rotation in degrees, clockwise.",
+        pub rotation() -> f32
+    );
+    make_virtual_wrapper!(
+        "?setRotation@CCNode@cocos2d@@UAEXM@Z",
+        "Sets the rotation in degrees, clockwise.",
+        pub set_rotation(rotation: f32)
+    );
+    make_virtual_wrapper!(
+        "?isVisible@CCNode@cocos2d@@UAE_NXZ",
+        "Returns whether the node (and its children) is drawn.",
+        pub is_visible() -> bool
+    );
+    make_virtual_wrapper!(
+        "?setVisible@CCNode@cocos2d@@UAEX_N@Z",
+        "Shows or hides the node and its children.",
+        pub set_visible(visible: bool)
+    );
+    make_virtual_wrapper!(
+        "?getZOrder@CCNode@cocos2d@@UAEHXZ",
+        "Returns the z-order relative to the node's siblings.",
+        pub z_order() -> i32
+    );
+    make_virtual_wrapper!(
+        "?setZOrder@CCNode@cocos2d@@UAEXH@Z",
+        "Sets the z-order relative to the node's siblings.",
+        pub set_z_order(z_order: i32)
+    );
+    make_virtual_wrapper!(
+        "?getTag@CCNode@cocos2d@@UBEHXZ",
+        "Returns the tag used to identify the node.",
+        pub tag() -> i32
+    );
+    make_virtual_wrapper!(
+        "?setTag@CCNode@cocos2d@@UAEXH@Z",
+        "Sets the tag used to identify the node.",
+        pub set_tag(tag: i32)
+    );
+    make_cocos_wrapper!(
+        "?getChildrenCount@CCNode@cocos2d@@QBEIXZ",
+        "Returns the number of direct children.",
+        pub children_count() -> u32
+    );
+    make_virtual_wrapper!(
+        "?addChild@CCNode@cocos2d@@UAEXPAV12@HH@Z",
+        "Adds a child with a z-order and a tag.",
+        pub add_child(child: CCNode, z_order: i32, tag: i32)
+    );
+    make_virtual_wrapper!(
+        "?removeFromParentAndCleanup@CCNode@cocos2d@@UAEX_N@Z",
+        "Removes the node from its parent, stopping its actions if `cleanup` is true.",
+        pub remove_from_parent(cleanup: bool)
+    );
+
+    /// Returns the parent node, if there is one.
+    #[inline]
+    pub fn parent(&self) -> Option<CCNode> {
+        CCNode::from_address(self.get_parent_raw()).to_option()
+    }
+
+    /// Returns the array of direct children. It is only allocated once the first
+    /// child is added.
+    #[inline]
+    pub fn children(&self) -> Option<CCArray> {
+        CCArray::from_address(self.get_children_raw()).to_option()
+    }
+
+    /// Returns the direct child at `index`.
+    pub fn child(&self, index: u32) -> Option<CCNode> {
+        let children = self.children()?;
+        if index >= children.count() {
+            return None;
+        }
+        CCNode::from_address(children.object_at(index)).to_option()
+    }
+
+    /// Returns the first direct child with the given tag.
+    #[inline]
+    pub fn child_by_tag(&self, tag: i32) -> Option<CCNode> {
+        CCNode::from_address(self.get_child_by_tag_raw(tag)).to_option()
+    }
+
+    /// Iterates over the direct children of the node.
+    pub fn iter_children(&self) -> impl Iterator<Item = CCNode> {
+        let children = self.children();
+        let count = children.map_or(0, |c| c.count());
+        children
+            .into_iter()
+            .flat_map(move |c| (0..count).map(move |i| CCNode::from_address(c.object_at(i))))
+            .filter(|node| !node.is_null())
+    }
+
+    /// Returns the name of the node's class, e.g. `PlayLayer`.
+    #[inline]
+    pub fn class_name(&self) -> Option<String> {
+        unsafe { rtti::class_name(self.address) }
+    }
+
+    /// Casts the node to a more specific class, if it is one (or derives from it).
+    ///
+    /// ```no_run
+    /// use geometrydash::{cocos2d::CCDirector, PlayLayer};
+    ///
+    /// let scene = CCDirector::shared().running_scene();
+    /// let play_layer = scene.descendants().find_map(|n| n.downcast::<PlayLayer>());
+    /// ```
+    #[inline]
+    pub fn downcast<T: RttiClass>(&self) -> Option<T> {
+        unsafe { rtti::downcast(self.address) }
+    }
+
+    /// Iterates over every node below this one (not including itself), depth-first,
+    /// in the order the children are stored.
+    pub fn descendants(&self) -> Descendants {
+        let mut stack = Vec::new();
+        push_children(&mut stack, *self);
+        Descendants { stack }
+    }
+}
+
+impl_addr_funcs!(CCNode);
+impl_rtti_class!(CCNode, "cocos2d::CCNode");
+
+/// Depth-first iterator over a node tree, created by [`CCNode::descendants`].
+#[derive(Debug, Clone)]
+pub struct Descendants {
+    stack: Vec<CCNode>,
+}
+
+/// Pushes the children of `node` in reverse, so they're popped in order.
+fn push_children(stack: &mut Vec<CCNode>, node: CCNode) {
+    let start = stack.len();
+    stack.extend(node.iter_children());
+    stack[start..].reverse();
+}
+
+impl Iterator for Descendants {
+    type Item = CCNode;
+
+    fn next(&mut self) -> Option<CCNode> {
+        let node = self.stack.pop()?;
+        push_children(&mut self.stack, node);
+        Some(node)
+    }
+}
@@ -1,7 +1,11 @@
 use crate::{impl_addr_funcs, Ptr};
 use windows::Win32::Foundation::FARPROC;
 
-use super::get_hmod;
+use super::{get_hmod, make_cocos_wrapper};
+
+/// A `void (CCObject::*)(float)` member function pointer, called with the
+/// target as `this` and the time since the last call.
+pub type SelSchedule = unsafe extern "thiscall" fn(Ptr, f32);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
@@ -24,6 +28,29 @@ impl CCScheduler {
             ))(self.address)
         }
     }
+
+    /// Set the timescale. Values below 1.0 slow the game down, values above speed it up.
+    pub fn set_timescale(&self, timescale: f32) {
+        unsafe {
+            (std::mem::transmute::<FARPROC, unsafe extern "thiscall" fn(Ptr, f32)>(
+                windows::Win32::System::LibraryLoader::GetProcAddress(
+                    get_hmod(),
+                    windows::core::s!("?setTimeScale@CCScheduler@cocos2d@@QAEXM@Z"),
+                ),
+            ))(self.address, timescale)
+        }
+    }
+
+    make_cocos_wrapper!(
+        "?scheduleSelector@CCScheduler@cocos2d@@QAEXP8CCObject@2@AEXM@ZPAV32@M_N@Z",
+        "Calls `selector` on `target` every `interval` seconds (every frame if 0).",
+        pub schedule_selector(selector: SelSchedule, target: Ptr, interval: f32, paused: bool)
+    );
+    make_cocos_wrapper!(
+        "?unscheduleSelector@CCScheduler@cocos2d@@QAEXP8CCObject@2@AEXM@ZPAV32@@Z",
+        "Stops calling a selector scheduled with [`CCScheduler::schedule_selector`].",
+        pub unschedule_selector(selector: SelSchedule, target: Ptr)
+    );
 }
 
 impl_addr_funcs!(CCScheduler);
@@ -0,0 +1,50 @@
+use super::make_cocos_wrapper;
+use crate::{impl_addr_funcs, Ptr};
+
+/// Phase of a touch, which is how cocos2d reports mouse clicks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum TouchType {
+    Began = 0,
+    Moved = 1,
+    Ended = 2,
+    Cancelled = 3,
+}
+
+impl TouchType {
+    pub fn from_raw(raw: u32) -> Option<Self> {
+        Some(match raw {
+            0 => Self::Began,
+            1 => Self::Moved,
+            2 => Self::Ended,
+            3 => Self::Cancelled,
+            _ => return None,
+        })
+    }
+}
+
+/// Sends touches (and mouse clicks) to the touch delegates (`cocos2d::CCTouchDispatcher`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct CCTouchDispatcher {
+    address: Ptr,
+}
+
+impl CCTouchDispatcher {
+    pub const fn from_address(address: Ptr) -> Self {
+        Self { address }
+    }
+
+    make_cocos_wrapper!(
+        "?isDispatchEvents@CCTouchDispatcher@cocos2d@@QAE_NXZ",
+        "Returns whether touches are sent to the delegates.",
+        pub is_dispatch_events() -> bool
+    );
+    make_cocos_wrapper!(
+        "?setDispatchEvents@CCTouchDispatcher@cocos2d@@QAEX_N@Z",
+        "Enables or disables sending touches to the delegates, e.g. to block mouse input.",
+        pub set_dispatch_events(dispatch: bool)
+    );
+}
+
+impl_addr_funcs!(CCTouchDispatcher);
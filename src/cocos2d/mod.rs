@@ -1,10 +1,28 @@
 mod ccapplication;
+mod ccarray;
+mod ccdictionary;
 mod ccdirector;
+mod ccdrawnode;
+mod ccgeometry;
+mod cckeyboarddispatcher;
+mod cclabelbmfont;
+mod ccnode;
 mod ccscheduler;
+mod cctouchdispatcher;
+mod vtable;
 
 pub use ccapplication::*;
+pub use ccarray::*;
+pub use ccdictionary::*;
 pub use ccdirector::*;
+pub use ccdrawnode::*;
+pub use ccgeometry::*;
+pub use cckeyboarddispatcher::*;
+pub use cclabelbmfont::*;
+pub use ccnode::*;
 pub use ccscheduler::*;
+pub use cctouchdispatcher::*;
+pub(crate) use vtable::VirtualFunction;
 use windows::Win32::Foundation::HMODULE;
 
 /// Get the `libcocos2d.dll` module handle.
@@ -13,6 +31,48 @@ pub fn get_hmod() -> HMODULE {
     unsafe { GetModuleHandleA(windows::core::s!("libcocos2d.dll")).unwrap() }
 }
 
+/// Generates a method that calls an exported `__thiscall` function of `libcocos2d.dll`.
+macro_rules! make_cocos_wrapper {
+    ($procname:literal, $comment:literal, $vis:vis $fnname:ident($($n:ident: $t:ty),*) $(-> $ret:ty)?) => {
+        #[doc = $comment]
+        #[inline]
+        $vis fn $fnname(&self, $($n: $t),*) $(-> $ret)? {
+            unsafe {
+                (std::mem::transmute::<
+                    windows::Win32::Foundation::FARPROC,
+                    unsafe extern "thiscall" fn($crate::Ptr, $($t),*) $(-> $ret)?,
+                >(windows::Win32::System::LibraryLoader::GetProcAddress(
+                    $crate::cocos2d::get_hmod(),
+                    windows::core::s!($procname),
+                )))(self.address, $($n),*)
+            }
+        }
+    };
+}
+
+pub(crate) use make_cocos_wrapper;
+
+/// Like [`make_cocos_wrapper`], for virtual functions (`U` in the mangled name).
+/// Calls through the vtable of the object, so overrides in derived classes run.
+macro_rules! make_virtual_wrapper {
+    ($procname:literal, $comment:literal, $vis:vis $fnname:ident($($n:ident: $t:ty),*) $(-> $ret:ty)?) => {
+        #[doc = $comment]
+        #[inline]
+        $vis fn $fnname(&self, $($n: $t),*) $(-> $ret)? {
+            static FUNCTION: $crate::cocos2d::VirtualFunction =
+                $crate::cocos2d::VirtualFunction::new(concat!($procname, "\0"));
+            unsafe {
+                (std::mem::transmute::<
+                    $crate::Ptr,
+                    unsafe extern "thiscall" fn($crate::Ptr, $($t),*) $(-> $ret)?,
+                >(FUNCTION.resolve(self.address)))(self.address, $($n),*)
+            }
+        }
+    };
+}
+
+pub(crate) use make_virtual_wrapper;
+
 /*
 #[macro_export]
 macro_rules! cocos2dx_procname {
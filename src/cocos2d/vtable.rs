@@ -0,0 +1,145 @@
+//! Virtual calls into `libcocos2d.dll`.
+//!
+//! Calling the exported symbol of a virtual function (`U` in the mangled name)
+//! always runs the base class' implementation, even for objects that override
+//! it. [`VirtualFunction`] finds the vtable slot of the function once and calls
+//! through the vtable of the object instead.
+//!
+//! The slot is looked up in the vtables the DLL exports for the class that
+//! declares the function (`??_7CCSprite@cocos2d@@6B...`), which every derived
+//! class shares the layout of.
+
+use super::get_hmod;
+use crate::{read_mem, read_ptr, Ptr};
+use std::{ffi::CStr, ops::Range, sync::OnceLock};
+use windows::{core::PCSTR, Win32::System::LibraryLoader::GetProcAddress};
+
+/// Where to call a virtual function from.
+#[derive(Debug, Clone, Copy)]
+enum Target {
+    /// Index in the vtable.
+    Slot(usize),
+    /// The slot wasn't found, call the exported function directly.
+    Export(Ptr),
+}
+
+/// An exported virtual function of `libcocos2d.dll`, see [`make_virtual_wrapper`](super::make_virtual_wrapper).
+pub(crate) struct VirtualFunction {
+    /// Mangled name, null terminated.
+    procname: &'static str,
+    target: OnceLock<Target>,
+}
+
+impl VirtualFunction {
+    pub const fn new(procname: &'static str) -> Self {
+        Self {
+            procname,
+            target: OnceLock::new(),
+        }
+    }
+
+    /// Returns the address of the function `object` runs when calling this one.
+    ///
+    /// # Safety
+    ///
+    /// `object` must point to an instance of the class declaring the function.
+    pub unsafe fn resolve(&self, object: Ptr) -> Ptr {
+        match *self.target.get_or_init(|| self.find()) {
+            Target::Slot(slot) => read_ptr(read_ptr(object) + slot * 4),
+            Target::Export(address) => address,
+        }
+    }
+
+    fn find(&self) -> Target {
+        let export = unsafe { GetProcAddress(get_hmod(), PCSTR(self.procname.as_ptr())) }
+            .map_or(0, |f| f as Ptr);
+        // ?setFlipX@CCSprite@cocos2d@@UAEX_N@Z
+        let class = self.procname.split('@').nth(1).unwrap_or_default();
+        let prefix = format!("??_7{class}@cocos2d@@6B");
+        let base = get_hmod().0 as Ptr;
+        let code = unsafe { code_sections(base) };
+        let slot = unsafe { exports(base) }
+            .filter(|(name, _)| name.to_bytes().starts_with(prefix.as_bytes()))
+            .find_map(|(_, vtable)| unsafe { primary_slot(vtable, export, &code) });
+        match slot {
+            Some(slot) => Target::Slot(slot),
+            None => {
+                log::warn!(
+                    "no vtable slot for {}, calling it non-virtually",
+                    self.procname.trim_end_matches('\0')
+                );
+                Target::Export(export)
+            }
+        }
+    }
+}
+
+/// Returns the index of `function` in `vtable`, if it is the vtable at the
+/// start of the object (secondary ones are used through adjusted pointers).
+///
+/// `code` are the executable sections of the module, the vtable ends at the
+/// first entry outside of them (the locator pointer of the next vtable, or data).
+unsafe fn primary_slot(vtable: Ptr, function: Ptr, code: &[Range<Ptr>]) -> Option<usize> {
+    // CompleteObjectLocator* = vtable[-1]
+    // u32 CompleteObjectLocator.offset = CompleteObjectLocator + 0x4
+    let locator = read_ptr(vtable - 4);
+    if function == 0 || locator == 0 || read_ptr(locator + 0x4) != 0 {
+        return None;
+    }
+    (0..)
+        .map(|i| read_ptr(vtable + i * 4))
+        .take_while(|entry| code.iter().any(|section| section.contains(entry)))
+        .position(|entry| entry == function)
+}
+
+/// Returns the address ranges of the executable sections of the module loaded at `base`.
+unsafe fn code_sections(base: Ptr) -> Vec<Range<Ptr>> {
+    const IMAGE_SCN_MEM_EXECUTE: u32 = 0x2000_0000;
+
+    // u16 IMAGE_NT_HEADERS32.FileHeader.NumberOfSections = NT + 0x6
+    // u16 IMAGE_NT_HEADERS32.FileHeader.SizeOfOptionalHeader = NT + 0x14
+    // IMAGE_SECTION_HEADER[] = NT + 0x18 + SizeOfOptionalHeader
+    let nt_headers = base + read_ptr(base + 0x3C);
+    let count = *read_mem::<u16>(nt_headers + 0x6) as Ptr;
+    let sections = nt_headers + 0x18 + *read_mem::<u16>(nt_headers + 0x14) as Ptr;
+
+    // IMAGE_SECTION_HEADER, 0x28 bytes
+    // u32 VirtualSize = + 0x8
+    // u32 VirtualAddress = + 0xC
+    // u32 Characteristics = + 0x24
+    (0..count)
+        .map(|i| sections + i * 0x28)
+        .filter(|&section| *read_mem::<u32>(section + 0x24) & IMAGE_SCN_MEM_EXECUTE != 0)
+        .map(|section| {
+            let start = base + read_ptr(section + 0xC);
+            start..start + read_ptr(section + 0x8)
+        })
+        .collect()
+}
+
+/// Iterates over the named exports of the module loaded at `base`.
+unsafe fn exports(base: Ptr) -> impl Iterator<Item = (&'static CStr, Ptr)> {
+    // u32 IMAGE_DOS_HEADER.e_lfanew = base + 0x3C
+    // IMAGE_DATA_DIRECTORY exports = IMAGE_NT_HEADERS32 + 0x78
+    let nt_headers = base + read_ptr(base + 0x3C);
+    let directory = base + read_ptr(nt_headers + 0x78);
+
+    // IMAGE_EXPORT_DIRECTORY
+    // u32 NumberOfNames = + 0x18
+    // u32 AddressOfFunctions = + 0x1C
+    // u32 AddressOfNames = + 0x20
+    // u32 AddressOfNameOrdinals = + 0x24
+    let count = if directory == base {
+        0
+    } else {
+        read_ptr(directory + 0x18)
+    };
+    let functions = base + read_ptr(directory + 0x1C);
+    let names = base + read_ptr(directory + 0x20);
+    let ordinals = base + read_ptr(directory + 0x24);
+    (0..count).map(move |i| {
+        let name = CStr::from_ptr((base + read_ptr(names + i * 4)) as *const _);
+        let ordinal = *((ordinals + i * 2) as *const u16) as Ptr;
+        (name, base + read_ptr(functions + ordinal * 4))
+    })
+}
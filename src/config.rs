@@ -0,0 +1,441 @@
+//! Per-mod settings stored as JSON.
+//!
+//! Settings are any serde type implementing [`Settings`]. The file stores them
+//! with the version of their schema:
+//!
+//! ```json
+//! { "version": 2, "settings": { "noclip": true, "restart_key": "Ctrl+R" } }
+//! ```
+//!
+//! Fields missing from the file take their default value, so adding a field
+//! doesn't need a new version. Renaming or changing a field does: bump
+//! [`Settings::VERSION`] and convert old files in [`Settings::migrate`].
+//!
+//! [`Config::poll`] reloads the file when it was edited (e.g. by hand while the
+//! game is running) and calls the listeners registered with [`Config::on_change`].
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// Settings of a mod.
+pub trait Settings: Serialize + DeserializeOwned + Default {
+    /// Version of the schema, stored in the file.
+    const VERSION: u32;
+
+    /// Converts `settings` from an older `version` of the schema to the current one.
+    ///
+    /// The default implementation keeps them as they are.
+    fn migrate(version: u32, settings: &mut Value) -> Result<(), ConfigError> {
+        let _ = (version, settings);
+        Ok(())
+    }
+}
+
+/// Errors that can happen when loading or saving settings.
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    /// The file was written by a newer version of the mod.
+    NewerVersion {
+        found: u32,
+        supported: u32,
+    },
+    /// [`Settings::migrate`] failed.
+    Migration(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "io error: {e}"),
+            Self::Json(e) => write!(f, "invalid settings: {e}"),
+            Self::NewerVersion { found, supported } => write!(
+                f,
+                "settings version {found} is newer than the supported version {supported}"
+            ),
+            Self::Migration(e) => write!(f, "can't migrate settings: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ConfigError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for ConfigError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct ConfigFile<T> {
+    version: u32,
+    settings: T,
+}
+
+/// Returns the game's save directory, `%LOCALAPPDATA%\GeometryDash`.
+pub fn save_dir() -> Option<PathBuf> {
+    std::env::var_os("LOCALAPPDATA").map(|dir| PathBuf::from(dir).join("GeometryDash"))
+}
+
+/// Returns where the settings of a mod are stored: `mods/<name>/config.json`
+/// in the game's save directory.
+pub fn config_path(mod_name: &str) -> Option<PathBuf> {
+    save_dir().map(|dir| dir.join("mods").join(mod_name).join("config.json"))
+}
+
+/// Copies the fields of `value` over `defaults`, recursing into objects.
+fn merge(defaults: &mut Value, value: Value) {
+    match (defaults, value) {
+        (Value::Object(defaults), Value::Object(value)) => {
+            for (key, value) in value {
+                match defaults.get_mut(&key) {
+                    Some(default) => merge(default, value),
+                    None => {
+                        defaults.insert(key, value);
+                    }
+                }
+            }
+        }
+        (defaults, value) => *defaults = value,
+    }
+}
+
+/// Parses a settings file, migrating and filling in defaults.
+pub fn parse<T: Settings>(text: &str) -> Result<T, ConfigError> {
+    #[derive(Deserialize)]
+    struct Raw {
+        version: u32,
+        settings: Value,
+    }
+
+    let Raw {
+        version,
+        mut settings,
+    } = serde_json::from_str(text)?;
+    if version > T::VERSION {
+        return Err(ConfigError::NewerVersion {
+            found: version,
+            supported: T::VERSION,
+        });
+    }
+    if version < T::VERSION {
+        T::migrate(version, &mut settings)?;
+    }
+    let mut merged = serde_json::to_value(T::default())?;
+    merge(&mut merged, settings);
+    Ok(serde_json::from_value(merged)?)
+}
+
+/// Serializes settings for a settings file.
+pub fn to_string<T: Settings>(settings: &T) -> Result<String, ConfigError> {
+    Ok(serde_json::to_string_pretty(&ConfigFile {
+        version: T::VERSION,
+        settings,
+    })?)
+}
+
+type Listener<T> = Box<dyn FnMut(&T) + Send>;
+
+/// Settings loaded from a file, saved on every change and reloaded when the
+/// file is edited.
+pub struct Config<T: Settings> {
+    path: PathBuf,
+    settings: T,
+    /// Contents of the file when it was last read or written.
+    contents: String,
+    listeners: Vec<Listener<T>>,
+    poll_interval: Duration,
+    last_poll: Option<Instant>,
+}
+
+impl<T: Settings + fmt::Debug> fmt::Debug for Config<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Config")
+            .field("path", &self.path)
+            .field("settings", &self.settings)
+            .finish()
+    }
+}
+
+impl<T: Settings> Config<T> {
+    /// Loads the settings at `path`, creating the file (and its directory) with
+    /// the defaults if it doesn't exist. Migrated files are saved again.
+    pub fn open<P: Into<PathBuf>>(path: P) -> Result<Self, ConfigError> {
+        let path = path.into();
+        let mut config = Self {
+            path,
+            settings: T::default(),
+            contents: String::new(),
+            listeners: Vec::new(),
+            poll_interval: Duration::from_secs(1),
+            last_poll: None,
+        };
+        match fs::read_to_string(&config.path) {
+            Ok(contents) => {
+                config.settings = parse(&contents)?;
+                config.contents = contents;
+                if to_string(&config.settings)? != config.contents {
+                    config.save()?;
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => config.save()?,
+            Err(e) => return Err(e.into()),
+        }
+        Ok(config)
+    }
+
+    /// Loads the settings of a mod from [`config_path`].
+    pub fn for_mod(mod_name: &str) -> Result<Self, ConfigError> {
+        let path = config_path(mod_name).ok_or_else(|| {
+            ConfigError::Io(io::Error::new(
+                io::ErrorKind::NotFound,
+                "LOCALAPPDATA isn't set",
+            ))
+        })?;
+        Self::open(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the current settings.
+    pub fn get(&self) -> &T {
+        &self.settings
+    }
+
+    /// Changes the settings and saves them.
+    pub fn update<F: FnOnce(&mut T)>(&mut self, f: F) -> Result<(), ConfigError> {
+        f(&mut self.settings);
+        self.save()
+    }
+
+    /// Resets the settings to their defaults and saves them.
+    pub fn reset(&mut self) -> Result<(), ConfigError> {
+        self.update(|settings| *settings = T::default())
+    }
+
+    /// Writes the settings to the file.
+    ///
+    /// The file is replaced at once, so the game never reads half of it.
+    pub fn save(&mut self) -> Result<(), ConfigError> {
+        let contents = to_string(&self.settings)?;
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let temp = self.path.with_extension("json.tmp");
+        fs::write(&temp, &contents)?;
+        fs::rename(&temp, &self.path)?;
+        self.contents = contents;
+        Ok(())
+    }
+
+    /// Registers a function called with the new settings when the file is reloaded.
+    pub fn on_change<F: FnMut(&T) + Send + 'static>(&mut self, listener: F) {
+        self.listeners.push(Box::new(listener));
+    }
+
+    /// Sets how often [`Config::poll`] reads the file. One second by default.
+    pub fn set_poll_interval(&mut self, interval: Duration) {
+        self.poll_interval = interval;
+    }
+
+    /// Reloads the file if it changed since it was last read or written, at
+    /// most once per poll interval. Call it regularly, e.g. every frame.
+    ///
+    /// Returns whether the settings were reloaded. If the file is invalid the
+    /// current settings are kept and the error is returned.
+    pub fn poll(&mut self) -> Result<bool, ConfigError> {
+        let now = Instant::now();
+        if self
+            .last_poll
+            .is_some_and(|last| now.duration_since(last) < self.poll_interval)
+        {
+            return Ok(false);
+        }
+        self.last_poll = Some(now);
+        self.reload()
+    }
+
+    /// Reloads the file right away if it changed, see [`Config::poll`].
+    pub fn reload(&mut self) -> Result<bool, ConfigError> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            // deleted: keep the current settings, they're written back on the next save
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(false),
+            Err(e) => return Err(e.into()),
+        };
+        if contents == self.contents {
+            return Ok(false);
+        }
+        let settings = parse(&contents);
+        // don't report the same broken file on every poll
+        self.contents = contents;
+        self.settings = settings?;
+        for listener in &mut self.listeners {
+            listener(&self.settings);
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct TestSettings {
+        noclip: bool,
+        restart_key: String,
+        color: [u8; 3],
+    }
+
+    impl Default for TestSettings {
+        fn default() -> Self {
+            Self {
+                noclip: false,
+                restart_key: "R".into(),
+                color: [255, 0, 0],
+            }
+        }
+    }
+
+    impl Settings for TestSettings {
+        const VERSION: u32 = 2;
+
+        // version 1 called the key "restart"
+        fn migrate(version: u32, settings: &mut Value) -> Result<(), ConfigError> {
+            if version < 2 {
+                let object = settings
+                    .as_object_mut()
+                    .ok_or_else(|| ConfigError::Migration("not an object".into()))?;
+                if let Some(key) = object.remove("restart") {
+                    object.insert("restart_key".into(), key);
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("geometrydash-config-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("config.json")
+    }
+
+    #[test]
+    fn defaults_and_migration() {
+        let settings: TestSettings =
+            parse(r#"{ "version": 2, "settings": { "noclip": true } }"#).unwrap();
+        assert_eq!(
+            settings,
+            TestSettings {
+                noclip: true,
+                ..Default::default()
+            }
+        );
+
+        let settings: TestSettings =
+            parse(r#"{ "version": 1, "settings": { "restart": "Ctrl+R" } }"#).unwrap();
+        assert_eq!(settings.restart_key, "Ctrl+R");
+
+        assert!(matches!(
+            parse::<TestSettings>(r#"{ "version": 3, "settings": {} }"#),
+            Err(ConfigError::NewerVersion {
+                found: 3,
+                supported: 2
+            })
+        ));
+        assert!(matches!(
+            parse::<TestSettings>(r#"{ "version": 2, "settings": { "noclip": 1 } }"#),
+            Err(ConfigError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn open_creates_and_saves() {
+        let path = temp_path("open");
+        let mut config = Config::<TestSettings>::open(&path).unwrap();
+        assert_eq!(*config.get(), TestSettings::default());
+        assert!(path.exists());
+
+        config.update(|s| s.noclip = true).unwrap();
+        let config = Config::<TestSettings>::open(&path).unwrap();
+        assert!(config.get().noclip);
+
+        // old files are upgraded on disk
+        fs::write(
+            &path,
+            r#"{ "version": 1, "settings": { "restart": "F5" } }"#,
+        )
+        .unwrap();
+        let config = Config::<TestSettings>::open(&path).unwrap();
+        assert_eq!(config.get().restart_key, "F5");
+        let saved = fs::read_to_string(&path).unwrap();
+        assert!(saved.contains("\"version\": 2") && saved.contains("restart_key"));
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn hot_reload() {
+        let path = temp_path("reload");
+        let mut config = Config::<TestSettings>::open(&path).unwrap();
+        config.set_poll_interval(Duration::ZERO);
+        let changes = Arc::new(AtomicU32::new(0));
+        let counter = changes.clone();
+        config.on_change(move |settings| {
+            assert!(settings.noclip);
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+
+        // our own writes don't count as changes
+        config.update(|s| s.color = [0, 0, 255]).unwrap();
+        assert!(!config.poll().unwrap());
+
+        fs::write(
+            &path,
+            r#"{ "version": 2, "settings": { "noclip": true, "color": [0, 0, 255] } }"#,
+        )
+        .unwrap();
+        assert!(config.poll().unwrap());
+        assert!(config.get().noclip);
+        assert_eq!(config.get().color, [0, 0, 255]);
+        assert!(!config.poll().unwrap());
+        assert_eq!(changes.load(Ordering::Relaxed), 1);
+
+        // broken files keep the current settings and are only reported once
+        fs::write(&path, "{ not json").unwrap();
+        assert!(config.poll().is_err());
+        assert!(!config.poll().unwrap());
+        assert!(config.get().noclip);
+        assert_eq!(changes.load(Ordering::Relaxed), 1);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
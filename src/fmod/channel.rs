@@ -0,0 +1,99 @@
+use super::*;
+use std::{marker::PhantomData, ptr::NonNull};
+
+/// A playing sound, borrowed from the game's FMOD system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Channel<'a> {
+    raw: NonNull<FMOD_CHANNEL>,
+    _marker: PhantomData<&'a FMOD_SYSTEM>,
+}
+
+impl_fmod_handle!(Channel, FMOD_CHANNEL);
+
+impl<'a> Channel<'a> {
+    impl_fmod_get_set!(
+        volume,
+        set_volume,
+        f32,
+        FMOD_Channel_GetVolume,
+        FMOD_Channel_SetVolume,
+        "volume (linear, 1.0 is full volume)"
+    );
+    impl_fmod_get_set!(
+        pitch,
+        set_pitch,
+        f32,
+        FMOD_Channel_GetPitch,
+        FMOD_Channel_SetPitch,
+        "pitch multiplier (also changes the playback speed)"
+    );
+    impl_fmod_get_set!(
+        frequency,
+        set_frequency,
+        f32,
+        FMOD_Channel_GetFrequency,
+        FMOD_Channel_SetFrequency,
+        "playback frequency in Hz"
+    );
+    impl_fmod_get_set_bool!(
+        is_paused,
+        set_paused,
+        FMOD_Channel_GetPaused,
+        FMOD_Channel_SetPaused,
+        "the channel is paused"
+    );
+    impl_fmod_get_set_bool!(
+        is_muted,
+        set_muted,
+        FMOD_Channel_GetMute,
+        FMOD_Channel_SetMute,
+        "the channel is muted"
+    );
+
+    /// Returns whether the channel is playing.
+    #[inline]
+    pub fn is_playing(&self) -> Result<bool> {
+        let mut playing: FMOD_BOOL = 0;
+        Error::check(unsafe { FMOD_Channel_IsPlaying(self.as_raw(), &mut playing) })?;
+        Ok(playing != 0)
+    }
+
+    /// Returns the playback position in milliseconds.
+    #[inline]
+    pub fn position(&self) -> Result<u32> {
+        let mut position = 0;
+        Error::check(unsafe {
+            FMOD_Channel_GetPosition(self.as_raw(), &mut position, FMOD_TIMEUNIT_MS)
+        })?;
+        Ok(position)
+    }
+
+    /// Seeks to the given position in milliseconds.
+    #[inline]
+    pub fn set_position(&self, position: u32) -> Result<()> {
+        Error::check(unsafe { FMOD_Channel_SetPosition(self.as_raw(), position, FMOD_TIMEUNIT_MS) })
+    }
+
+    /// Stops the channel. The handle becomes invalid after this.
+    #[inline]
+    pub fn stop(&self) -> Result<()> {
+        Error::check(unsafe { FMOD_Channel_Stop(self.as_raw()) })
+    }
+
+    /// Returns the sound that is currently playing on this channel.
+    #[inline]
+    pub fn current_sound(&self) -> Result<Option<Sound<'a>>> {
+        let mut sound = std::ptr::null_mut();
+        Error::check(unsafe { FMOD_Channel_GetCurrentSound(self.as_raw(), &mut sound) })?;
+        Ok(unsafe { Sound::from_raw(sound) })
+    }
+
+    /// Returns the channel group this channel outputs to.
+    #[inline]
+    pub fn channel_group(&self) -> Result<Option<ChannelGroup<'a>>> {
+        let mut group = std::ptr::null_mut();
+        Error::check(unsafe { FMOD_Channel_GetChannelGroup(self.as_raw(), &mut group) })?;
+        Ok(unsafe { ChannelGroup::from_raw(group) })
+    }
+}
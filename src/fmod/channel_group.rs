@@ -0,0 +1,97 @@
+use super::*;
+use std::{marker::PhantomData, ptr::NonNull};
+
+/// A group of channels mixed together, borrowed from the game's FMOD system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct ChannelGroup<'a> {
+    raw: NonNull<FMOD_CHANNELGROUP>,
+    _marker: PhantomData<&'a FMOD_SYSTEM>,
+}
+
+impl_fmod_handle!(ChannelGroup, FMOD_CHANNELGROUP);
+
+impl<'a> ChannelGroup<'a> {
+    impl_fmod_get_set!(
+        volume,
+        set_volume,
+        f32,
+        FMOD_ChannelGroup_GetVolume,
+        FMOD_ChannelGroup_SetVolume,
+        "volume (linear, 1.0 is full volume)"
+    );
+    impl_fmod_get_set!(
+        pitch,
+        set_pitch,
+        f32,
+        FMOD_ChannelGroup_GetPitch,
+        FMOD_ChannelGroup_SetPitch,
+        "pitch multiplier (also changes the playback speed)"
+    );
+    impl_fmod_get_set_bool!(
+        is_paused,
+        set_paused,
+        FMOD_ChannelGroup_GetPaused,
+        FMOD_ChannelGroup_SetPaused,
+        "the group is paused"
+    );
+    impl_fmod_get_set_bool!(
+        is_muted,
+        set_muted,
+        FMOD_ChannelGroup_GetMute,
+        FMOD_ChannelGroup_SetMute,
+        "the group is muted"
+    );
+
+    /// Stops all channels in this group.
+    #[inline]
+    pub fn stop(&self) -> Result<()> {
+        Error::check(unsafe { FMOD_ChannelGroup_Stop(self.as_raw()) })
+    }
+
+    /// Returns the number of channels directly in this group.
+    #[inline]
+    pub fn num_channels(&self) -> Result<usize> {
+        let mut num = 0;
+        Error::check(unsafe { FMOD_ChannelGroup_GetNumChannels(self.as_raw(), &mut num) })?;
+        Ok(num.max(0) as usize)
+    }
+
+    /// Returns the channel at the given index.
+    #[inline]
+    pub fn channel(&self, index: usize) -> Result<Option<Channel<'a>>> {
+        let mut channel = std::ptr::null_mut();
+        Error::check(unsafe {
+            FMOD_ChannelGroup_GetChannel(self.as_raw(), index as _, &mut channel)
+        })?;
+        Ok(unsafe { Channel::from_raw(channel) })
+    }
+
+    /// Returns the number of DSPs attached to this group.
+    #[inline]
+    pub fn num_dsps(&self) -> Result<usize> {
+        let mut num = 0;
+        Error::check(unsafe { FMOD_ChannelGroup_GetNumDSPs(self.as_raw(), &mut num) })?;
+        Ok(num.max(0) as usize)
+    }
+
+    /// Returns the DSP at the given index. Index 0 is the head of the chain.
+    #[inline]
+    pub fn dsp(&self, index: usize) -> Result<Option<Dsp<'a>>> {
+        let mut dsp = std::ptr::null_mut();
+        Error::check(unsafe { FMOD_ChannelGroup_GetDSP(self.as_raw(), index as _, &mut dsp) })?;
+        Ok(unsafe { Dsp::from_raw(dsp) })
+    }
+
+    /// Inserts a DSP into this group's chain at the given index.
+    #[inline]
+    pub fn add_dsp(&self, index: usize, dsp: Dsp<'_>) -> Result<()> {
+        Error::check(unsafe { FMOD_ChannelGroup_AddDSP(self.as_raw(), index as _, dsp.as_raw()) })
+    }
+
+    /// Removes a DSP from this group's chain.
+    #[inline]
+    pub fn remove_dsp(&self, dsp: Dsp<'_>) -> Result<()> {
+        Error::check(unsafe { FMOD_ChannelGroup_RemoveDSP(self.as_raw(), dsp.as_raw()) })
+    }
+}
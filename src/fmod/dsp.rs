@@ -0,0 +1,46 @@
+use super::*;
+use std::{marker::PhantomData, ptr::NonNull};
+
+/// A DSP unit in the mixer graph, borrowed from the game's FMOD system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Dsp<'a> {
+    raw: NonNull<FMOD_DSP>,
+    _marker: PhantomData<&'a FMOD_SYSTEM>,
+}
+
+impl_fmod_handle!(Dsp, FMOD_DSP);
+
+impl<'a> Dsp<'a> {
+    impl_fmod_get_set_bool!(
+        is_active,
+        set_active,
+        FMOD_DSP_GetActive,
+        FMOD_DSP_SetActive,
+        "the DSP is processing"
+    );
+    impl_fmod_get_set_bool!(
+        is_bypassed,
+        set_bypassed,
+        FMOD_DSP_GetBypass,
+        FMOD_DSP_SetBypass,
+        "the DSP is bypassed (passes audio through untouched)"
+    );
+
+    /// Returns the name of the DSP.
+    pub fn name(&self) -> Result<String> {
+        let mut buf = [0 as std::ffi::c_char; 32];
+        Error::check(unsafe {
+            FMOD_DSP_GetInfo(
+                self.as_raw(),
+                buf.as_mut_ptr(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        })?;
+        let name = unsafe { std::ffi::CStr::from_ptr(buf.as_ptr()) };
+        Ok(name.to_string_lossy().into_owned())
+    }
+}
@@ -0,0 +1,488 @@
+use super::*;
+
+/// Convenience alias for results of FMOD calls.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// An error returned by FMOD, converted from an `FMOD_RESULT`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// Tried to call a function on a data type that does not allow this type of functionality (ie calling Sound::lock on a streaming sound).
+    BadCommand,
+    /// Error trying to allocate a channel.
+    ChannelAlloc,
+    /// The specified channel has been reused to play another sound.
+    ChannelStolen,
+    /// DMA Failure.  See debug output for more information.
+    Dma,
+    /// DSP connection error.  Connection possibly caused a cyclic dependency or connected dsps with incompatible buffer counts.
+    DspConnection,
+    /// DSP return code from a DSP process query callback.  Tells mixer not to call the process callback and therefore not consume CPU.  Use this to optimize the DSP graph.
+    DspDontProcess,
+    /// DSP Format error.  A DSP unit may have attempted to connect to this network with the wrong format, or a matrix may have been set with the wrong size if the target unit has a specified channel map.
+    DspFormat,
+    /// DSP is already in the mixer's DSP network. It must be removed before being reinserted or released.
+    DspInUse,
+    /// DSP connection error.  Couldn't find the DSP unit specified.
+    DspNotFound,
+    /// DSP operation error.  Cannot perform operation on this DSP as it is reserved by the system.
+    DspReserved,
+    /// DSP return code from a DSP process query callback.  Tells mixer silence would be produced from read, so go idle and not consume CPU.  Use this to optimize the DSP graph.
+    DspSilence,
+    /// DSP operation cannot be performed on a DSP of this type.
+    DspType,
+    /// Error loading file.
+    FileBad,
+    /// Couldn't perform seek operation.  This is a limitation of the medium (ie netstreams) or the file format.
+    FileCouldNotSeek,
+    /// Media was ejected while reading.
+    FileDiskEjected,
+    /// End of file unexpectedly reached while trying to read essential data (truncated?).
+    FileEof,
+    /// End of current chunk reached while trying to read data.
+    FileEndOfData,
+    /// File not found.
+    FileNotFound,
+    /// Unsupported file or audio format.
+    Format,
+    /// There is a version mismatch between the FMOD header and either the FMOD Studio library or the FMOD Low Level library.
+    HeaderMismatch,
+    /// A HTTP error occurred. This is a catch-all for HTTP errors not listed elsewhere.
+    Http,
+    /// The specified resource requires authentication or is forbidden.
+    HttpAccess,
+    /// Proxy authentication is required to access the specified resource.
+    HttpProxyAuth,
+    /// A HTTP server error occurred.
+    HttpServerError,
+    /// The HTTP request timed out.
+    HttpTimeout,
+    /// FMOD was not initialized correctly to support this function.
+    Initialization,
+    /// Cannot call this command after System::init.
+    Initialized,
+    /// An error occurred that wasn't supposed to.  Contact support.
+    Internal,
+    /// Value passed in was a NaN, Inf or denormalized float.
+    InvalidFloat,
+    /// An invalid object handle was used.
+    InvalidHandle,
+    /// An invalid parameter was passed to this function.
+    InvalidParam,
+    /// An invalid seek position was passed to this function.
+    InvalidPosition,
+    /// An invalid speaker was passed to this function based on the current speaker mode.
+    InvalidSpeaker,
+    /// The syncpoint did not come from this sound handle.
+    InvalidSyncPoint,
+    /// Tried to call a function on a thread that is not supported.
+    InvalidThread,
+    /// The vectors passed in are not unit length, or perpendicular.
+    InvalidVector,
+    /// Reached maximum audible playback count for this sound's soundgroup.
+    MaxAudible,
+    /// Not enough memory or resources.
+    Memory,
+    /// Can't use FMOD_OPENMEMORY_POINT on non PCM source data, or non mp3/xma/adpcm data if FMOD_CREATECOMPRESSEDSAMPLE was used.
+    MemoryCantPoint,
+    /// Tried to call a command on a 2d sound when the command was meant for 3d sound.
+    Needs3d,
+    /// Tried to use a feature that requires hardware support.
+    NeedsHardware,
+    /// Couldn't connect to the specified host.
+    NetConnect,
+    /// A socket error occurred.  This is a catch-all for socket-related errors not listed elsewhere.
+    NetSocketError,
+    /// The specified URL couldn't be resolved.
+    NetUrl,
+    /// Operation on a non-blocking socket could not complete immediately.
+    NetWouldBlock,
+    /// Operation could not be performed because specified sound/DSP connection is not ready.
+    NotReady,
+    /// Error initializing output device, but more specifically, the output device is already in use and cannot be reused.
+    OutputAllocated,
+    /// Error creating hardware sound buffer.
+    OutputCreateBuffer,
+    /// A call to a standard soundcard driver failed, which could possibly mean a bug in the driver or resources were missing or exhausted.
+    OutputDriverCall,
+    /// Soundcard does not support the specified format.
+    OutputFormat,
+    /// Error initializing output device.
+    OutputInit,
+    /// The output device has no drivers installed.  If pre-init, FMOD_OUTPUT_NOSOUND is selected as the output mode.  If post-init, the function just fails.
+    OutputNoDrivers,
+    /// An unspecified error has been returned from a plugin.
+    Plugin,
+    /// A requested output, dsp unit type or codec was not available.
+    PluginMissing,
+    /// A resource that the plugin requires cannot be found. (ie the DLS file for MIDI playback)
+    PluginResource,
+    /// A plugin was built with an unsupported SDK version.
+    PluginVersion,
+    /// An error occurred trying to initialize the recording device.
+    Record,
+    /// Reverb properties cannot be set on this channel because a parent channelgroup owns the reverb connection.
+    ReverbChannelGroup,
+    /// Specified instance in FMOD_REVERB_PROPERTIES couldn't be set. Most likely because it is an invalid instance number or the reverb doesn't exist.
+    ReverbInstance,
+    /// The error occurred because the sound referenced contains subsounds when it shouldn't have, or it doesn't contain subsounds when it should have.  The operation may also not be able to be performed on a parent sound.
+    Subsounds,
+    /// This subsound is already being used by another sound, you cannot have more than one parent to a sound.  Null out the other parent's entry first.
+    SubsoundAllocated,
+    /// Shared subsounds cannot be replaced or moved from their parent stream, such as when the parent stream is an FSB file.
+    SubsoundCantMove,
+    /// The specified tag could not be found or there are no tags.
+    TagNotFound,
+    /// The sound created exceeds the allowable input channel count.  This can be increased using the 'maxinputchannels' parameter in System::setSoftwareFormat.
+    TooManyChannels,
+    /// The retrieved string is too long to fit in the supplied buffer and has been truncated.
+    Truncated,
+    /// Something in FMOD hasn't been implemented when it should be! contact support!
+    Unimplemented,
+    /// This command failed because System::init or System::setDriver was not called.
+    Uninitialized,
+    /// A command issued was not supported by this object.  Possibly a plugin without certain callbacks specified.
+    Unsupported,
+    /// The version number of this file format is not supported.
+    Version,
+    /// The specified bank has already been loaded.
+    EventAlreadyLoaded,
+    /// The live update connection failed due to the game already being connected.
+    EventLiveUpdateBusy,
+    /// The live update connection failed due to the game data being out of sync with the tool.
+    EventLiveUpdateMismatch,
+    /// The live update connection timed out.
+    EventLiveUpdateTimeout,
+    /// The requested event, parameter, bus or vca could not be found.
+    EventNotFound,
+    /// The Studio::System object is not yet initialized.
+    StudioUninitialized,
+    /// The specified resource is not loaded, so it can't be unloaded.
+    StudioNotLoaded,
+    /// An invalid string was passed to this function.
+    InvalidString,
+    /// The specified resource is already locked.
+    AlreadyLocked,
+    /// The specified resource is not locked, so it can't be unlocked.
+    NotLocked,
+    /// The specified recording driver has been disconnected.
+    RecordDisconnected,
+    /// The length provided exceeds the allowable limit.
+    TooManySamples,
+    /// An error code not known to these bindings.
+    Unknown(FMOD_RESULT),
+}
+
+impl Error {
+    /// Converts an `FMOD_RESULT` into a [`Result`].
+    pub fn check(result: FMOD_RESULT) -> Result<()> {
+        match Self::from_raw(result) {
+            None => Ok(()),
+            Some(e) => Err(e),
+        }
+    }
+
+    /// Converts an `FMOD_RESULT` into an [`Error`], returning `None` for `FMOD_OK`.
+    pub fn from_raw(result: FMOD_RESULT) -> Option<Self> {
+        Some(match result {
+            FMOD_OK => return None,
+            FMOD_ERR_BADCOMMAND => Self::BadCommand,
+            FMOD_ERR_CHANNEL_ALLOC => Self::ChannelAlloc,
+            FMOD_ERR_CHANNEL_STOLEN => Self::ChannelStolen,
+            FMOD_ERR_DMA => Self::Dma,
+            FMOD_ERR_DSP_CONNECTION => Self::DspConnection,
+            FMOD_ERR_DSP_DONTPROCESS => Self::DspDontProcess,
+            FMOD_ERR_DSP_FORMAT => Self::DspFormat,
+            FMOD_ERR_DSP_INUSE => Self::DspInUse,
+            FMOD_ERR_DSP_NOTFOUND => Self::DspNotFound,
+            FMOD_ERR_DSP_RESERVED => Self::DspReserved,
+            FMOD_ERR_DSP_SILENCE => Self::DspSilence,
+            FMOD_ERR_DSP_TYPE => Self::DspType,
+            FMOD_ERR_FILE_BAD => Self::FileBad,
+            FMOD_ERR_FILE_COULDNOTSEEK => Self::FileCouldNotSeek,
+            FMOD_ERR_FILE_DISKEJECTED => Self::FileDiskEjected,
+            FMOD_ERR_FILE_EOF => Self::FileEof,
+            FMOD_ERR_FILE_ENDOFDATA => Self::FileEndOfData,
+            FMOD_ERR_FILE_NOTFOUND => Self::FileNotFound,
+            FMOD_ERR_FORMAT => Self::Format,
+            FMOD_ERR_HEADER_MISMATCH => Self::HeaderMismatch,
+            FMOD_ERR_HTTP => Self::Http,
+            FMOD_ERR_HTTP_ACCESS => Self::HttpAccess,
+            FMOD_ERR_HTTP_PROXY_AUTH => Self::HttpProxyAuth,
+            FMOD_ERR_HTTP_SERVER_ERROR => Self::HttpServerError,
+            FMOD_ERR_HTTP_TIMEOUT => Self::HttpTimeout,
+            FMOD_ERR_INITIALIZATION => Self::Initialization,
+            FMOD_ERR_INITIALIZED => Self::Initialized,
+            FMOD_ERR_INTERNAL => Self::Internal,
+            FMOD_ERR_INVALID_FLOAT => Self::InvalidFloat,
+            FMOD_ERR_INVALID_HANDLE => Self::InvalidHandle,
+            FMOD_ERR_INVALID_PARAM => Self::InvalidParam,
+            FMOD_ERR_INVALID_POSITION => Self::InvalidPosition,
+            FMOD_ERR_INVALID_SPEAKER => Self::InvalidSpeaker,
+            FMOD_ERR_INVALID_SYNCPOINT => Self::InvalidSyncPoint,
+            FMOD_ERR_INVALID_THREAD => Self::InvalidThread,
+            FMOD_ERR_INVALID_VECTOR => Self::InvalidVector,
+            FMOD_ERR_MAXAUDIBLE => Self::MaxAudible,
+            FMOD_ERR_MEMORY => Self::Memory,
+            FMOD_ERR_MEMORY_CANTPOINT => Self::MemoryCantPoint,
+            FMOD_ERR_NEEDS3D => Self::Needs3d,
+            FMOD_ERR_NEEDSHARDWARE => Self::NeedsHardware,
+            FMOD_ERR_NET_CONNECT => Self::NetConnect,
+            FMOD_ERR_NET_SOCKET_ERROR => Self::NetSocketError,
+            FMOD_ERR_NET_URL => Self::NetUrl,
+            FMOD_ERR_NET_WOULD_BLOCK => Self::NetWouldBlock,
+            FMOD_ERR_NOTREADY => Self::NotReady,
+            FMOD_ERR_OUTPUT_ALLOCATED => Self::OutputAllocated,
+            FMOD_ERR_OUTPUT_CREATEBUFFER => Self::OutputCreateBuffer,
+            FMOD_ERR_OUTPUT_DRIVERCALL => Self::OutputDriverCall,
+            FMOD_ERR_OUTPUT_FORMAT => Self::OutputFormat,
+            FMOD_ERR_OUTPUT_INIT => Self::OutputInit,
+            FMOD_ERR_OUTPUT_NODRIVERS => Self::OutputNoDrivers,
+            FMOD_ERR_PLUGIN => Self::Plugin,
+            FMOD_ERR_PLUGIN_MISSING => Self::PluginMissing,
+            FMOD_ERR_PLUGIN_RESOURCE => Self::PluginResource,
+            FMOD_ERR_PLUGIN_VERSION => Self::PluginVersion,
+            FMOD_ERR_RECORD => Self::Record,
+            FMOD_ERR_REVERB_CHANNELGROUP => Self::ReverbChannelGroup,
+            FMOD_ERR_REVERB_INSTANCE => Self::ReverbInstance,
+            FMOD_ERR_SUBSOUNDS => Self::Subsounds,
+            FMOD_ERR_SUBSOUND_ALLOCATED => Self::SubsoundAllocated,
+            FMOD_ERR_SUBSOUND_CANTMOVE => Self::SubsoundCantMove,
+            FMOD_ERR_TAGNOTFOUND => Self::TagNotFound,
+            FMOD_ERR_TOOMANYCHANNELS => Self::TooManyChannels,
+            FMOD_ERR_TRUNCATED => Self::Truncated,
+            FMOD_ERR_UNIMPLEMENTED => Self::Unimplemented,
+            FMOD_ERR_UNINITIALIZED => Self::Uninitialized,
+            FMOD_ERR_UNSUPPORTED => Self::Unsupported,
+            FMOD_ERR_VERSION => Self::Version,
+            FMOD_ERR_EVENT_ALREADY_LOADED => Self::EventAlreadyLoaded,
+            FMOD_ERR_EVENT_LIVEUPDATE_BUSY => Self::EventLiveUpdateBusy,
+            FMOD_ERR_EVENT_LIVEUPDATE_MISMATCH => Self::EventLiveUpdateMismatch,
+            FMOD_ERR_EVENT_LIVEUPDATE_TIMEOUT => Self::EventLiveUpdateTimeout,
+            FMOD_ERR_EVENT_NOTFOUND => Self::EventNotFound,
+            FMOD_ERR_STUDIO_UNINITIALIZED => Self::StudioUninitialized,
+            FMOD_ERR_STUDIO_NOT_LOADED => Self::StudioNotLoaded,
+            FMOD_ERR_INVALID_STRING => Self::InvalidString,
+            FMOD_ERR_ALREADY_LOCKED => Self::AlreadyLocked,
+            FMOD_ERR_NOT_LOCKED => Self::NotLocked,
+            FMOD_ERR_RECORD_DISCONNECTED => Self::RecordDisconnected,
+            FMOD_ERR_TOOMANYSAMPLES => Self::TooManySamples,
+            other => Self::Unknown(other),
+        })
+    }
+
+    /// Returns the raw `FMOD_RESULT` of this error.
+    pub fn raw(&self) -> FMOD_RESULT {
+        match self {
+            Self::BadCommand => FMOD_ERR_BADCOMMAND,
+            Self::ChannelAlloc => FMOD_ERR_CHANNEL_ALLOC,
+            Self::ChannelStolen => FMOD_ERR_CHANNEL_STOLEN,
+            Self::Dma => FMOD_ERR_DMA,
+            Self::DspConnection => FMOD_ERR_DSP_CONNECTION,
+            Self::DspDontProcess => FMOD_ERR_DSP_DONTPROCESS,
+            Self::DspFormat => FMOD_ERR_DSP_FORMAT,
+            Self::DspInUse => FMOD_ERR_DSP_INUSE,
+            Self::DspNotFound => FMOD_ERR_DSP_NOTFOUND,
+            Self::DspReserved => FMOD_ERR_DSP_RESERVED,
+            Self::DspSilence => FMOD_ERR_DSP_SILENCE,
+            Self::DspType => FMOD_ERR_DSP_TYPE,
+            Self::FileBad => FMOD_ERR_FILE_BAD,
+            Self::FileCouldNotSeek => FMOD_ERR_FILE_COULDNOTSEEK,
+            Self::FileDiskEjected => FMOD_ERR_FILE_DISKEJECTED,
+            Self::FileEof => FMOD_ERR_FILE_EOF,
+            Self::FileEndOfData => FMOD_ERR_FILE_ENDOFDATA,
+            Self::FileNotFound => FMOD_ERR_FILE_NOTFOUND,
+            Self::Format => FMOD_ERR_FORMAT,
+            Self::HeaderMismatch => FMOD_ERR_HEADER_MISMATCH,
+            Self::Http => FMOD_ERR_HTTP,
+            Self::HttpAccess => FMOD_ERR_HTTP_ACCESS,
+            Self::HttpProxyAuth => FMOD_ERR_HTTP_PROXY_AUTH,
+            Self::HttpServerError => FMOD_ERR_HTTP_SERVER_ERROR,
+            Self::HttpTimeout => FMOD_ERR_HTTP_TIMEOUT,
+            Self::Initialization => FMOD_ERR_INITIALIZATION,
+            Self::Initialized => FMOD_ERR_INITIALIZED,
+            Self::Internal => FMOD_ERR_INTERNAL,
+            Self::InvalidFloat => FMOD_ERR_INVALID_FLOAT,
+            Self::InvalidHandle => FMOD_ERR_INVALID_HANDLE,
+            Self::InvalidParam => FMOD_ERR_INVALID_PARAM,
+            Self::InvalidPosition => FMOD_ERR_INVALID_POSITION,
+            Self::InvalidSpeaker => FMOD_ERR_INVALID_SPEAKER,
+            Self::InvalidSyncPoint => FMOD_ERR_INVALID_SYNCPOINT,
+            Self::InvalidThread => FMOD_ERR_INVALID_THREAD,
+            Self::InvalidVector => FMOD_ERR_INVALID_VECTOR,
+            Self::MaxAudible => FMOD_ERR_MAXAUDIBLE,
+            Self::Memory => FMOD_ERR_MEMORY,
+            Self::MemoryCantPoint => FMOD_ERR_MEMORY_CANTPOINT,
+            Self::Needs3d => FMOD_ERR_NEEDS3D,
+            Self::NeedsHardware => FMOD_ERR_NEEDSHARDWARE,
+            Self::NetConnect => FMOD_ERR_NET_CONNECT,
+            Self::NetSocketError => FMOD_ERR_NET_SOCKET_ERROR,
+            Self::NetUrl => FMOD_ERR_NET_URL,
+            Self::NetWouldBlock => FMOD_ERR_NET_WOULD_BLOCK,
+            Self::NotReady => FMOD_ERR_NOTREADY,
+            Self::OutputAllocated => FMOD_ERR_OUTPUT_ALLOCATED,
+            Self::OutputCreateBuffer => FMOD_ERR_OUTPUT_CREATEBUFFER,
+            Self::OutputDriverCall => FMOD_ERR_OUTPUT_DRIVERCALL,
+            Self::OutputFormat => FMOD_ERR_OUTPUT_FORMAT,
+            Self::OutputInit => FMOD_ERR_OUTPUT_INIT,
+            Self::OutputNoDrivers => FMOD_ERR_OUTPUT_NODRIVERS,
+            Self::Plugin => FMOD_ERR_PLUGIN,
+            Self::PluginMissing => FMOD_ERR_PLUGIN_MISSING,
+            Self::PluginResource => FMOD_ERR_PLUGIN_RESOURCE,
+            Self::PluginVersion => FMOD_ERR_PLUGIN_VERSION,
+            Self::Record => FMOD_ERR_RECORD,
+            Self::ReverbChannelGroup => FMOD_ERR_REVERB_CHANNELGROUP,
+            Self::ReverbInstance => FMOD_ERR_REVERB_INSTANCE,
+            Self::Subsounds => FMOD_ERR_SUBSOUNDS,
+            Self::SubsoundAllocated => FMOD_ERR_SUBSOUND_ALLOCATED,
+            Self::SubsoundCantMove => FMOD_ERR_SUBSOUND_CANTMOVE,
+            Self::TagNotFound => FMOD_ERR_TAGNOTFOUND,
+            Self::TooManyChannels => FMOD_ERR_TOOMANYCHANNELS,
+            Self::Truncated => FMOD_ERR_TRUNCATED,
+            Self::Unimplemented => FMOD_ERR_UNIMPLEMENTED,
+            Self::Uninitialized => FMOD_ERR_UNINITIALIZED,
+            Self::Unsupported => FMOD_ERR_UNSUPPORTED,
+            Self::Version => FMOD_ERR_VERSION,
+            Self::EventAlreadyLoaded => FMOD_ERR_EVENT_ALREADY_LOADED,
+            Self::EventLiveUpdateBusy => FMOD_ERR_EVENT_LIVEUPDATE_BUSY,
+            Self::EventLiveUpdateMismatch => FMOD_ERR_EVENT_LIVEUPDATE_MISMATCH,
+            Self::EventLiveUpdateTimeout => FMOD_ERR_EVENT_LIVEUPDATE_TIMEOUT,
+            Self::EventNotFound => FMOD_ERR_EVENT_NOTFOUND,
+            Self::StudioUninitialized => FMOD_ERR_STUDIO_UNINITIALIZED,
+            Self::StudioNotLoaded => FMOD_ERR_STUDIO_NOT_LOADED,
+            Self::InvalidString => FMOD_ERR_INVALID_STRING,
+            Self::AlreadyLocked => FMOD_ERR_ALREADY_LOCKED,
+            Self::NotLocked => FMOD_ERR_NOT_LOCKED,
+            Self::RecordDisconnected => FMOD_ERR_RECORD_DISCONNECTED,
+            Self::TooManySamples => FMOD_ERR_TOOMANYSAMPLES,
+            Self::Unknown(result) => *result,
+        }
+    }
+
+    /// Returns the description of this error, same as `FMOD_ErrorString`.
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::BadCommand => "Tried to call a function on a data type that does not allow this type of functionality (ie calling Sound::lock on a streaming sound).",
+            Self::ChannelAlloc => "Error trying to allocate a channel.",
+            Self::ChannelStolen => "The specified channel has been reused to play another sound.",
+            Self::Dma => "DMA Failure.  See debug output for more information.",
+            Self::DspConnection => "DSP connection error.  Connection possibly caused a cyclic dependency or connected dsps with incompatible buffer counts.",
+            Self::DspDontProcess => "DSP return code from a DSP process query callback.  Tells mixer not to call the process callback and therefore not consume CPU.  Use this to optimize the DSP graph.",
+            Self::DspFormat => "DSP Format error.  A DSP unit may have attempted to connect to this network with the wrong format, or a matrix may have been set with the wrong size if the target unit has a specified channel map.",
+            Self::DspInUse => "DSP is already in the mixer's DSP network. It must be removed before being reinserted or released.",
+            Self::DspNotFound => "DSP connection error.  Couldn't find the DSP unit specified.",
+            Self::DspReserved => "DSP operation error.  Cannot perform operation on this DSP as it is reserved by the system.",
+            Self::DspSilence => "DSP return code from a DSP process query callback.  Tells mixer silence would be produced from read, so go idle and not consume CPU.  Use this to optimize the DSP graph.",
+            Self::DspType => "DSP operation cannot be performed on a DSP of this type.",
+            Self::FileBad => "Error loading file.",
+            Self::FileCouldNotSeek => "Couldn't perform seek operation.  This is a limitation of the medium (ie netstreams) or the file format.",
+            Self::FileDiskEjected => "Media was ejected while reading.",
+            Self::FileEof => "End of file unexpectedly reached while trying to read essential data (truncated?).",
+            Self::FileEndOfData => "End of current chunk reached while trying to read data.",
+            Self::FileNotFound => "File not found.",
+            Self::Format => "Unsupported file or audio format.",
+            Self::HeaderMismatch => "There is a version mismatch between the FMOD header and either the FMOD Studio library or the FMOD Low Level library.",
+            Self::Http => "A HTTP error occurred. This is a catch-all for HTTP errors not listed elsewhere.",
+            Self::HttpAccess => "The specified resource requires authentication or is forbidden.",
+            Self::HttpProxyAuth => "Proxy authentication is required to access the specified resource.",
+            Self::HttpServerError => "A HTTP server error occurred.",
+            Self::HttpTimeout => "The HTTP request timed out.",
+            Self::Initialization => "FMOD was not initialized correctly to support this function.",
+            Self::Initialized => "Cannot call this command after System::init.",
+            Self::Internal => "An error occurred that wasn't supposed to.  Contact support.",
+            Self::InvalidFloat => "Value passed in was a NaN, Inf or denormalized float.",
+            Self::InvalidHandle => "An invalid object handle was used.",
+            Self::InvalidParam => "An invalid parameter was passed to this function.",
+            Self::InvalidPosition => "An invalid seek position was passed to this function.",
+            Self::InvalidSpeaker => "An invalid speaker was passed to this function based on the current speaker mode.",
+            Self::InvalidSyncPoint => "The syncpoint did not come from this sound handle.",
+            Self::InvalidThread => "Tried to call a function on a thread that is not supported.",
+            Self::InvalidVector => "The vectors passed in are not unit length, or perpendicular.",
+            Self::MaxAudible => "Reached maximum audible playback count for this sound's soundgroup.",
+            Self::Memory => "Not enough memory or resources.",
+            Self::MemoryCantPoint => "Can't use FMOD_OPENMEMORY_POINT on non PCM source data, or non mp3/xma/adpcm data if FMOD_CREATECOMPRESSEDSAMPLE was used.",
+            Self::Needs3d => "Tried to call a command on a 2d sound when the command was meant for 3d sound.",
+            Self::NeedsHardware => "Tried to use a feature that requires hardware support.",
+            Self::NetConnect => "Couldn't connect to the specified host.",
+            Self::NetSocketError => "A socket error occurred.  This is a catch-all for socket-related errors not listed elsewhere.",
+            Self::NetUrl => "The specified URL couldn't be resolved.",
+            Self::NetWouldBlock => "Operation on a non-blocking socket could not complete immediately.",
+            Self::NotReady => "Operation could not be performed because specified sound/DSP connection is not ready.",
+            Self::OutputAllocated => "Error initializing output device, but more specifically, the output device is already in use and cannot be reused.",
+            Self::OutputCreateBuffer => "Error creating hardware sound buffer.",
+            Self::OutputDriverCall => "A call to a standard soundcard driver failed, which could possibly mean a bug in the driver or resources were missing or exhausted.",
+            Self::OutputFormat => "Soundcard does not support the specified format.",
+            Self::OutputInit => "Error initializing output device.",
+            Self::OutputNoDrivers => "The output device has no drivers installed.  If pre-init, FMOD_OUTPUT_NOSOUND is selected as the output mode.  If post-init, the function just fails.",
+            Self::Plugin => "An unspecified error has been returned from a plugin.",
+            Self::PluginMissing => "A requested output, dsp unit type or codec was not available.",
+            Self::PluginResource => "A resource that the plugin requires cannot be found. (ie the DLS file for MIDI playback)",
+            Self::PluginVersion => "A plugin was built with an unsupported SDK version.",
+            Self::Record => "An error occurred trying to initialize the recording device.",
+            Self::ReverbChannelGroup => "Reverb properties cannot be set on this channel because a parent channelgroup owns the reverb connection.",
+            Self::ReverbInstance => "Specified instance in FMOD_REVERB_PROPERTIES couldn't be set. Most likely because it is an invalid instance number or the reverb doesn't exist.",
+            Self::Subsounds => "The error occurred because the sound referenced contains subsounds when it shouldn't have, or it doesn't contain subsounds when it should have.  The operation may also not be able to be performed on a parent sound.",
+            Self::SubsoundAllocated => "This subsound is already being used by another sound, you cannot have more than one parent to a sound.  Null out the other parent's entry first.",
+            Self::SubsoundCantMove => "Shared subsounds cannot be replaced or moved from their parent stream, such as when the parent stream is an FSB file.",
+            Self::TagNotFound => "The specified tag could not be found or there are no tags.",
+            Self::TooManyChannels => "The sound created exceeds the allowable input channel count.  This can be increased using the 'maxinputchannels' parameter in System::setSoftwareFormat.",
+            Self::Truncated => "The retrieved string is too long to fit in the supplied buffer and has been truncated.",
+            Self::Unimplemented => "Something in FMOD hasn't been implemented when it should be! contact support!",
+            Self::Uninitialized => "This command failed because System::init or System::setDriver was not called.",
+            Self::Unsupported => "A command issued was not supported by this object.  Possibly a plugin without certain callbacks specified.",
+            Self::Version => "The version number of this file format is not supported.",
+            Self::EventAlreadyLoaded => "The specified bank has already been loaded.",
+            Self::EventLiveUpdateBusy => "The live update connection failed due to the game already being connected.",
+            Self::EventLiveUpdateMismatch => "The live update connection failed due to the game data being out of sync with the tool.",
+            Self::EventLiveUpdateTimeout => "The live update connection timed out.",
+            Self::EventNotFound => "The requested event, parameter, bus or vca could not be found.",
+            Self::StudioUninitialized => "The Studio::System object is not yet initialized.",
+            Self::StudioNotLoaded => "The specified resource is not loaded, so it can't be unloaded.",
+            Self::InvalidString => "An invalid string was passed to this function.",
+            Self::AlreadyLocked => "The specified resource is already locked.",
+            Self::NotLocked => "The specified resource is not locked, so it can't be unlocked.",
+            Self::RecordDisconnected => "The specified recording driver has been disconnected.",
+            Self::TooManySamples => "The length provided exceeds the allowable limit.",
+            Self::Unknown(_) => "Unknown error.",
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Error> for FMOD_RESULT {
+    fn from(value: Error) -> FMOD_RESULT {
+        value.raw()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_round_trip() {
+        assert_eq!(Error::from_raw(FMOD_OK), None);
+        assert_eq!(Error::check(FMOD_OK), Ok(()));
+        let unknown = Error::Unknown(0).message();
+        for raw in 1..=FMOD_ERR_TOOMANYSAMPLES {
+            let error = Error::from_raw(raw).unwrap();
+            assert_eq!(error.raw(), raw);
+            assert_eq!(FMOD_RESULT::from(error), raw);
+            assert!(!matches!(error, Error::Unknown(_)), "{raw}");
+            assert_ne!(error.message(), unknown, "{error:?}");
+        }
+        assert_eq!(Error::from_raw(FMOD_ERR_FILE_NOTFOUND), Some(Error::FileNotFound));
+        assert_eq!(Error::FileNotFound.to_string(), "File not found.");
+
+        let error = Error::from_raw(1000).unwrap();
+        assert_eq!(error, Error::Unknown(1000));
+        assert_eq!(error.raw(), 1000);
+        assert_eq!(Error::check(1000), Err(error));
+    }
+}
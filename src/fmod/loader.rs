@@ -0,0 +1,113 @@
+//! Resolves FMOD functions from the `fmod.dll` loaded by the game.
+
+#[cfg(not(feature = "link-fmod"))]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Declares the FMOD functions.
+///
+/// With the `link-fmod` feature this is a plain `extern` block, resolved by the
+/// linker from `fmod_vc.lib`. Otherwise, every function is a small wrapper that
+/// looks its symbol up in `fmod.dll` on the first call and returns
+/// `FMOD_ERR_UNINITIALIZED` if the library (or the symbol) isn't available.
+#[cfg(feature = "link-fmod")]
+macro_rules! fmod_functions {
+    ($(pub fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> FMOD_RESULT;)*) => {
+        extern "system" {
+            $(pub fn $name($($arg: $ty),*) -> FMOD_RESULT;)*
+        }
+    };
+}
+
+#[cfg(not(feature = "link-fmod"))]
+macro_rules! fmod_functions {
+    ($(pub fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> FMOD_RESULT;)*) => {
+        $(
+            #[allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+            pub unsafe fn $name($($arg: $ty),*) -> FMOD_RESULT {
+                static ADDRESS: std::sync::atomic::AtomicUsize =
+                    std::sync::atomic::AtomicUsize::new(0);
+                match $crate::fmod::loader::resolve(&ADDRESS, concat!(stringify!($name), "\0")) {
+                    Some(address) => {
+                        let func: unsafe extern "system" fn($($ty),*) -> FMOD_RESULT =
+                            std::mem::transmute(address);
+                        func($($arg),*)
+                    }
+                    None => FMOD_ERR_UNINITIALIZED,
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use fmod_functions;
+
+/// Returns whether the FMOD functions can be called.
+///
+/// Always `true` with the `link-fmod` feature.
+#[cfg(feature = "link-fmod")]
+#[inline]
+pub fn is_loaded() -> bool {
+    true
+}
+
+/// Returns whether the FMOD functions can be called.
+///
+/// Always `true` with the `link-fmod` feature.
+#[cfg(not(feature = "link-fmod"))]
+pub fn is_loaded() -> bool {
+    module() != 0
+}
+
+/// Returns the address of `name` (a nul-terminated symbol name), caching it in `cache`.
+///
+/// Failed lookups aren't cached, so calls made before the game has loaded
+/// `fmod.dll` start working once it has.
+#[cfg(not(feature = "link-fmod"))]
+#[doc(hidden)]
+pub fn resolve(cache: &AtomicUsize, name: &str) -> Option<usize> {
+    let address = cache.load(Ordering::Relaxed);
+    if address != 0 {
+        return Some(address);
+    }
+    let address = symbol(module(), name)?;
+    cache.store(address, Ordering::Relaxed);
+    Some(address)
+}
+
+#[cfg(all(game_bindings, not(feature = "link-fmod")))]
+fn module() -> usize {
+    use windows::{core::s, Win32::System::LibraryLoader::GetModuleHandleA};
+
+    static MODULE: AtomicUsize = AtomicUsize::new(0);
+    let module = MODULE.load(Ordering::Relaxed);
+    if module != 0 {
+        return module;
+    }
+    let module = unsafe { GetModuleHandleA(s!("fmod.dll")) }.map_or(0, |h| h.0 as usize);
+    MODULE.store(module, Ordering::Relaxed);
+    module
+}
+
+#[cfg(all(game_bindings, not(feature = "link-fmod")))]
+fn symbol(module: usize, name: &str) -> Option<usize> {
+    use windows::{
+        core::PCSTR,
+        Win32::{Foundation::HMODULE, System::LibraryLoader::GetProcAddress},
+    };
+
+    if module == 0 {
+        return None;
+    }
+    unsafe { GetProcAddress(HMODULE(module as _), PCSTR(name.as_ptr())) }.map(|f| f as usize)
+}
+
+// there is no fmod.dll to load outside of the game
+#[cfg(all(not(game_bindings), not(feature = "link-fmod")))]
+fn module() -> usize {
+    0
+}
+
+#[cfg(all(not(game_bindings), not(feature = "link-fmod")))]
+fn symbol(_module: usize, _name: &str) -> Option<usize> {
+    None
+}
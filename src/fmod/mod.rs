@@ -1,3 +1,94 @@
 #![allow(non_upper_case_globals, non_camel_case_types, non_snake_case)]
 
+#[cfg(not(feature = "regenerate-bindings"))]
+include!("bindings.rs");
+#[cfg(feature = "regenerate-bindings")]
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+pub mod loader;
+
+mod channel;
+mod channel_group;
+mod dsp;
+mod error;
+mod functions;
+mod sound;
+mod system;
+
+pub use channel::*;
+pub use channel_group::*;
+pub use dsp::*;
+pub use error::*;
+pub use functions::*;
+pub use sound::*;
+pub use system::*;
+
+/// Implements a safe wrapper type around a raw FMOD handle.
+///
+/// The wrappers don't own the handle, they borrow it from the game for `'a`.
+macro_rules! impl_fmod_handle {
+    ($t:ident, $raw:ty) => {
+        impl<'a> $t<'a> {
+            /// Wraps a raw handle, returning `None` if it is null.
+            ///
+            /// # Safety
+            ///
+            /// The handle must be valid for the lifetime `'a`.
+            #[inline]
+            pub unsafe fn from_raw(raw: *mut $raw) -> Option<Self> {
+                std::ptr::NonNull::new(raw).map(|raw| Self {
+                    raw,
+                    _marker: std::marker::PhantomData,
+                })
+            }
+
+            /// Returns the raw handle.
+            #[inline(always)]
+            pub fn as_raw(&self) -> *mut $raw {
+                self.raw.as_ptr()
+            }
+        }
+    };
+}
+
+/// Generates a getter and a setter for a value-typed FMOD property.
+macro_rules! impl_fmod_get_set {
+    ($get:ident, $set:ident, $typ:ty, $raw_get:ident, $raw_set:ident, $doc:literal) => {
+        #[doc = concat!("Returns the ", $doc, ".")]
+        #[inline]
+        pub fn $get(&self) -> $crate::fmod::Result<$typ> {
+            let mut value = <$typ>::default();
+            $crate::fmod::Error::check(unsafe { $raw_get(self.as_raw(), &mut value) })?;
+            Ok(value)
+        }
+
+        #[doc = concat!("Sets the ", $doc, ".")]
+        #[inline]
+        pub fn $set(&self, value: $typ) -> $crate::fmod::Result<()> {
+            $crate::fmod::Error::check(unsafe { $raw_set(self.as_raw(), value) })
+        }
+    };
+}
+
+/// Generates a getter and a setter for an `FMOD_BOOL` property.
+macro_rules! impl_fmod_get_set_bool {
+    ($get:ident, $set:ident, $raw_get:ident, $raw_set:ident, $doc:literal) => {
+        #[doc = concat!("Returns whether ", $doc, ".")]
+        #[inline]
+        pub fn $get(&self) -> $crate::fmod::Result<bool> {
+            let mut value: $crate::fmod::FMOD_BOOL = 0;
+            $crate::fmod::Error::check(unsafe { $raw_get(self.as_raw(), &mut value) })?;
+            Ok(value != 0)
+        }
+
+        #[doc = concat!("Sets whether ", $doc, ".")]
+        #[inline]
+        pub fn $set(&self, value: bool) -> $crate::fmod::Result<()> {
+            $crate::fmod::Error::check(unsafe { $raw_set(self.as_raw(), value as _) })
+        }
+    };
+}
+
+pub(crate) use impl_fmod_get_set;
+pub(crate) use impl_fmod_get_set_bool;
+pub(crate) use impl_fmod_handle;
@@ -0,0 +1,45 @@
+use super::*;
+use std::{marker::PhantomData, ptr::NonNull};
+
+/// A loaded sound, borrowed from the game's FMOD system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Sound<'a> {
+    raw: NonNull<FMOD_SOUND>,
+    _marker: PhantomData<&'a FMOD_SYSTEM>,
+}
+
+impl_fmod_handle!(Sound, FMOD_SOUND);
+
+impl<'a> Sound<'a> {
+    /// Returns the length of the sound in milliseconds.
+    #[inline]
+    pub fn length(&self) -> Result<u32> {
+        let mut length = 0;
+        Error::check(unsafe {
+            FMOD_Sound_GetLength(self.as_raw(), &mut length, FMOD_TIMEUNIT_MS)
+        })?;
+        Ok(length)
+    }
+
+    /// Returns the default playback frequency of the sound in Hz.
+    #[inline]
+    pub fn default_frequency(&self) -> Result<f32> {
+        let mut frequency = 0.0;
+        let mut priority = 0;
+        Error::check(unsafe {
+            FMOD_Sound_GetDefaults(self.as_raw(), &mut frequency, &mut priority)
+        })?;
+        Ok(frequency)
+    }
+
+    /// Returns the name of the sound (usually the file name).
+    pub fn name(&self) -> Result<String> {
+        let mut buf = [0 as std::ffi::c_char; 256];
+        Error::check(unsafe {
+            FMOD_Sound_GetName(self.as_raw(), buf.as_mut_ptr(), buf.len() as _)
+        })?;
+        let name = unsafe { std::ffi::CStr::from_ptr(buf.as_ptr()) };
+        Ok(name.to_string_lossy().into_owned())
+    }
+}
@@ -0,0 +1,85 @@
+use super::*;
+use std::{marker::PhantomData, ptr::NonNull};
+
+/// The game's FMOD system.
+///
+/// This doesn't own the system, it is borrowed from
+/// [`FMODAudioEngine`](crate::FMODAudioEngine) and never released.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct System<'a> {
+    raw: NonNull<FMOD_SYSTEM>,
+    _marker: PhantomData<&'a FMOD_SYSTEM>,
+}
+
+impl_fmod_handle!(System, FMOD_SYSTEM);
+
+impl<'a> System<'a> {
+    /// Returns the FMOD version as `0xaaaabbcc` (product, major, minor).
+    #[inline]
+    pub fn version(&self) -> Result<u32> {
+        let mut version = 0;
+        Error::check(unsafe { FMOD_System_GetVersion(self.as_raw(), &mut version) })?;
+        Ok(version)
+    }
+
+    /// Returns the output sample rate in Hz.
+    #[inline]
+    pub fn sample_rate(&self) -> Result<u32> {
+        let mut sample_rate = 0;
+        Error::check(unsafe {
+            FMOD_System_GetSoftwareFormat(
+                self.as_raw(),
+                &mut sample_rate,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        })?;
+        Ok(sample_rate.max(0) as u32)
+    }
+
+    /// Returns the number of output channels of the mixer (2 for stereo).
+    #[inline]
+    pub fn output_channels(&self) -> Result<u16> {
+        let mut speaker_mode: FMOD_SPEAKERMODE = 0;
+        let mut channels = 0;
+        Error::check(unsafe {
+            FMOD_System_GetSoftwareFormat(
+                self.as_raw(),
+                std::ptr::null_mut(),
+                &mut speaker_mode,
+                std::ptr::null_mut(),
+            )
+        })?;
+        Error::check(unsafe {
+            FMOD_System_GetSpeakerModeChannels(self.as_raw(), speaker_mode, &mut channels)
+        })?;
+        Ok(channels.max(0) as u16)
+    }
+
+    /// Returns the number of channels that are currently playing.
+    #[inline]
+    pub fn channels_playing(&self) -> Result<usize> {
+        let mut channels = 0;
+        Error::check(unsafe {
+            FMOD_System_GetChannelsPlaying(self.as_raw(), &mut channels, std::ptr::null_mut())
+        })?;
+        Ok(channels.max(0) as usize)
+    }
+
+    /// Returns the channel with the given ID.
+    #[inline]
+    pub fn channel(&self, id: i32) -> Result<Option<Channel<'a>>> {
+        let mut channel = std::ptr::null_mut();
+        Error::check(unsafe { FMOD_System_GetChannel(self.as_raw(), id, &mut channel) })?;
+        Ok(unsafe { Channel::from_raw(channel) })
+    }
+
+    /// Returns the master channel group, which everything is mixed into.
+    #[inline]
+    pub fn master_channel_group(&self) -> Result<ChannelGroup<'a>> {
+        let mut group = std::ptr::null_mut();
+        Error::check(unsafe { FMOD_System_GetMasterChannelGroup(self.as_raw(), &mut group) })?;
+        unsafe { ChannelGroup::from_raw(group) }.ok_or(Error::InvalidHandle)
+    }
+}
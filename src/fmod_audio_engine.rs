@@ -1,5 +1,5 @@
 use crate::{
-    fmod::{FMOD_CHANNEL, FMOD_SYSTEM},
+    fmod::{self, FMOD_CHANNEL, FMOD_SYSTEM},
     get_base, impl_addr_funcs, impl_get_set, Ptr,
 };
 use std::ffi::c_void;
@@ -27,6 +27,18 @@ impl FMODAudioEngine {
         0x130
     );
     impl_get_set!(extra_driver_data, set_extra_driver_data, *mut c_void, 0x140);
+
+    /// Returns the game's FMOD system, borrowed from the engine.
+    #[inline]
+    pub fn fmod_system(&self) -> Option<fmod::System<'_>> {
+        unsafe { fmod::System::from_raw(self.system()) }
+    }
+
+    /// Returns the channel the current song is playing on, borrowed from the engine.
+    #[inline]
+    pub fn music_channel(&self) -> Option<fmod::Channel<'_>> {
+        unsafe { fmod::Channel::from_raw(self.current_sound_channel()) }
+    }
 }
 
 impl_addr_funcs!(FMODAudioEngine);
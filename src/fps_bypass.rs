@@ -0,0 +1,283 @@
+#[cfg(game_bindings)]
+use crate::{cocos2d::CCApplication, PlayLayer};
+
+/// Fixed timestep accumulator.
+///
+/// Collects variable frame times and hands out a whole number of fixed-size
+/// physics steps, carrying the remainder over to the next frame. This doesn't
+/// touch the game at all.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedStep {
+    step: f64,
+    accumulator: f64,
+    max_steps: u32,
+}
+
+impl FixedStep {
+    /// Default limit for the number of steps in a single frame.
+    pub const DEFAULT_MAX_STEPS: u32 = 16;
+
+    /// Creates an accumulator that runs at `tps` ticks per second.
+    ///
+    /// # Panics
+    ///
+    /// If `tps` isn't a positive, finite number.
+    pub fn new(tps: f64) -> Self {
+        assert_valid_rate(tps, "tick rate");
+        Self {
+            step: 1.0 / tps,
+            accumulator: 0.0,
+            max_steps: Self::DEFAULT_MAX_STEPS,
+        }
+    }
+
+    /// Length of one step in seconds.
+    #[inline]
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    /// Ticks per second.
+    #[inline]
+    pub fn tps(&self) -> f64 {
+        1.0 / self.step
+    }
+
+    /// Changes the tick rate, keeping the accumulated time.
+    ///
+    /// # Panics
+    ///
+    /// If `tps` isn't a positive, finite number.
+    pub fn set_tps(&mut self, tps: f64) {
+        assert_valid_rate(tps, "tick rate");
+        self.step = 1.0 / tps;
+    }
+
+    /// Maximum number of steps returned by a single [`FixedStep::advance`] call.
+    ///
+    /// Time beyond that is dropped, so a long hitch (e.g. a lag spike or a
+    /// breakpoint) doesn't turn into hundreds of physics steps.
+    #[inline]
+    pub fn max_steps(&self) -> u32 {
+        self.max_steps
+    }
+
+    /// Sets the maximum number of steps per frame (at least 1).
+    pub fn set_max_steps(&mut self, max_steps: u32) {
+        self.max_steps = max_steps.max(1);
+    }
+
+    /// Time that has been accumulated but not yet stepped.
+    #[inline]
+    pub fn accumulator(&self) -> f64 {
+        self.accumulator
+    }
+
+    /// How far we are between the last step and the next one, in `[0.0, 1.0)`.
+    /// Useful for interpolating rendered positions.
+    #[inline]
+    pub fn alpha(&self) -> f64 {
+        self.accumulator / self.step
+    }
+
+    /// Adds a frame of `dt` seconds and returns how many steps should run.
+    pub fn advance(&mut self, dt: f64) -> u32 {
+        if dt.is_finite() && dt > 0.0 {
+            self.accumulator += dt;
+        }
+
+        // the small epsilon stops float error from eating a step when `dt`
+        // is an exact multiple of the step size
+        let steps = ((self.accumulator + 1e-9) / self.step).floor();
+        if steps > self.max_steps as f64 {
+            self.accumulator = 0.0;
+            return self.max_steps;
+        }
+
+        let steps = steps as u32;
+        self.accumulator = (self.accumulator - steps as f64 * self.step).max(0.0);
+        steps
+    }
+
+    /// Drops any accumulated time, e.g. when the level restarts.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.accumulator = 0.0;
+    }
+}
+
+#[inline]
+#[track_caller]
+fn assert_valid_rate(rate: f64, name: &str) {
+    assert!(
+        rate.is_finite() && rate > 0.0,
+        "{name} must be positive and finite, got {rate}"
+    );
+}
+
+/// Runs rendering and physics at independent rates.
+///
+/// The render rate is set through [`CCApplication::set_animation_interval`].
+/// The physics rate is driven by calling [`FpsBypass::update`] from a
+/// `PlayLayer::update` hook instead of the original, which then calls the
+/// original with a fixed `dt` as many times as needed.
+#[cfg(game_bindings)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FpsBypass {
+    fps: f64,
+    stepper: FixedStep,
+}
+
+#[cfg(game_bindings)]
+impl FpsBypass {
+    /// Creates a new bypass that renders at `fps` and simulates at `tps`.
+    ///
+    /// # Panics
+    ///
+    /// If `fps` or `tps` isn't a positive, finite number.
+    pub fn new(fps: f64, tps: f64) -> Self {
+        assert_valid_rate(fps, "frame rate");
+        Self {
+            fps,
+            stepper: FixedStep::new(tps),
+        }
+    }
+
+    /// Render frames per second.
+    #[inline]
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+
+    /// Physics ticks per second.
+    #[inline]
+    pub fn tps(&self) -> f64 {
+        self.stepper.tps()
+    }
+
+    /// Sets the render framerate and applies it.
+    ///
+    /// # Panics
+    ///
+    /// If `fps` isn't a positive, finite number.
+    pub fn set_fps(&mut self, fps: f64) {
+        assert_valid_rate(fps, "frame rate");
+        self.fps = fps;
+        self.apply();
+    }
+
+    /// Sets the physics tickrate. Takes effect on the next update.
+    ///
+    /// # Panics
+    ///
+    /// If `tps` isn't a positive, finite number.
+    pub fn set_tps(&mut self, tps: f64) {
+        self.stepper.set_tps(tps);
+    }
+
+    /// Returns the underlying accumulator.
+    #[inline]
+    pub fn stepper(&mut self) -> &mut FixedStep {
+        &mut self.stepper
+    }
+
+    /// Applies the render framerate to the game.
+    pub fn apply(&self) {
+        CCApplication::shared().set_animation_interval(1.0 / self.fps);
+    }
+
+    /// Advances physics by `dt` seconds, calling `update` once per fixed step.
+    ///
+    /// `update` is usually the original (unhooked) `PlayLayer::update`, or
+    /// [`PlayLayer::update`] itself if nothing is hooked.
+    pub fn update<F: FnMut(PlayLayer, f32)>(
+        &mut self,
+        play_layer: PlayLayer,
+        dt: f32,
+        mut update: F,
+    ) {
+        let step = self.stepper.step() as f32;
+        for _ in 0..self.stepper.advance(dt as f64) {
+            update(play_layer, step);
+        }
+    }
+
+    /// Restores the default 60 FPS and drops any accumulated time.
+    pub fn reset(&mut self) {
+        self.fps = 60.0;
+        self.stepper.reset();
+        self.apply();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_multiples() {
+        let mut stepper = FixedStep::new(240.0);
+        assert_eq!(stepper.tps(), 240.0);
+        // a 60 fps frame is exactly 4 steps, over and over
+        for _ in 0..1000 {
+            assert_eq!(stepper.advance(1.0 / 60.0), 4);
+        }
+        assert!(stepper.accumulator() < 1e-6);
+    }
+
+    #[test]
+    fn carries_the_remainder() {
+        let mut stepper = FixedStep::new(100.0);
+        assert_eq!(stepper.advance(0.025), 2);
+        assert!((stepper.alpha() - 0.5).abs() < 1e-6);
+        assert_eq!(stepper.advance(0.005), 1);
+        assert!(stepper.accumulator() < 1e-6);
+        assert_eq!(stepper.advance(0.004), 0);
+        // bad frame times are ignored
+        assert_eq!(stepper.advance(-1.0), 0);
+        assert_eq!(stepper.advance(f64::NAN), 0);
+        assert!((stepper.accumulator() - 0.004).abs() < 1e-9);
+
+        // changing the rate keeps the accumulated time
+        stepper.set_tps(500.0);
+        assert_eq!(stepper.advance(0.0), 2);
+    }
+
+    #[test]
+    fn clamps_and_resets() {
+        let mut stepper = FixedStep::new(60.0);
+        assert_eq!(stepper.max_steps(), FixedStep::DEFAULT_MAX_STEPS);
+        // a one second hitch runs the maximum and drops the rest
+        assert_eq!(stepper.advance(1.0), FixedStep::DEFAULT_MAX_STEPS);
+        assert_eq!(stepper.accumulator(), 0.0);
+        stepper.set_max_steps(0);
+        assert_eq!(stepper.advance(1.0), 1);
+
+        stepper.advance(0.01);
+        assert!(stepper.accumulator() > 0.0);
+        stepper.reset();
+        assert_eq!(stepper.accumulator(), 0.0);
+        assert_eq!(stepper.advance(0.01), 0);
+    }
+
+    #[test]
+    fn rejects_bad_tick_rates() {
+        for tps in [0.0, -60.0, f64::NAN, f64::INFINITY] {
+            assert!(std::panic::catch_unwind(|| FixedStep::new(tps)).is_err());
+            let mut stepper = FixedStep::new(60.0);
+            assert!(
+                std::panic::catch_unwind(move || stepper.set_tps(tps)).is_err(),
+                "{tps}"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(game_bindings)]
+    fn rejects_bad_frame_rates() {
+        for fps in [0.0, -60.0, f64::NAN, f64::INFINITY] {
+            assert!(std::panic::catch_unwind(|| FpsBypass::new(fps, 240.0)).is_err());
+        }
+        assert_eq!(FpsBypass::new(144.0, 240.0).fps(), 144.0);
+    }
+}
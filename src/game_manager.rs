@@ -65,3 +65,4 @@ impl GameManager {
 }
 
 crate::impl_addr_funcs!(GameManager);
+crate::rtti::impl_rtti_class!(GameManager, "GameManager");
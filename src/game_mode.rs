@@ -0,0 +1,61 @@
+/// Player gamemode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameMode {
+    /// Cube gamemode.
+    Cube,
+    /// Ship gamemode.
+    Ship,
+    /// UFO (bird) gamemode.
+    Ufo,
+    /// Ball gamemode.
+    Ball,
+    /// Wave (dart) gamemode.
+    Wave,
+    /// Robot gamemode.
+    Robot,
+    /// Spider gamemode.
+    Spider,
+}
+
+impl GameMode {
+    /// Converts the game's `IconType` value (as stored in start positions) to a gamemode.
+    pub const fn from_raw(raw: i32) -> Option<Self> {
+        Some(match raw {
+            0 => Self::Cube,
+            1 => Self::Ship,
+            2 => Self::Ball,
+            3 => Self::Ufo,
+            4 => Self::Wave,
+            5 => Self::Robot,
+            6 => Self::Spider,
+            _ => return None,
+        })
+    }
+
+    /// Returns the game's `IconType` value of this gamemode.
+    pub const fn raw(self) -> i32 {
+        match self {
+            Self::Cube => 0,
+            Self::Ship => 1,
+            Self::Ball => 2,
+            Self::Ufo => 3,
+            Self::Wave => 4,
+            Self::Robot => 5,
+            Self::Spider => 6,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_values() {
+        for raw in 0..7 {
+            assert_eq!(GameMode::from_raw(raw).unwrap().raw(), raw);
+        }
+        assert_eq!(GameMode::from_raw(2), Some(GameMode::Ball));
+        assert_eq!(GameMode::from_raw(7), None);
+    }
+}
@@ -1,4 +1,31 @@
-use crate::{impl_addr_funcs, impl_get_set, Ptr};
+// https://github.com/maxnut/gd.h/blob/436af2c6440a7efd1ba4720e48429fddaaf298e4/sprite_nodes/GameObject.h#L62-L194
+//
+// float GameObject.rotationX = GameObject + 0x20 (CCNode)
+// float GameObject.rotationY = GameObject + 0x24 (CCNode)
+// float GameObject.scaleX = GameObject + 0x28 (CCNode)
+// float GameObject.scaleY = GameObject + 0x2C (CCNode)
+// CCPoint GameObject.position = GameObject + 0x34 (CCNode)
+// float GameObject.objectRadius = GameObject + 0x2B4
+// CCRect GameObject.objectRect = GameObject + 0x2B8
+// GameObjectType GameObject.objectType = GameObject + 0x31C
+// CCPoint GameObject.startPosition = GameObject + 0x32C
+// int GameObject.objectID = GameObject + 0x360
+// short* GameObject.groups = GameObject + 0x384
+// short GameObject.groupCount = GameObject + 0x3C2
+// int GameObject.mainColorID = GameObject + 0x3C4
+// int GameObject.secondaryColorID = GameObject + 0x3C8
+// int GameObject.editorLayer = GameObject + 0x3CC
+// int GameObject.editorLayer2 = GameObject + 0x3D0
+// bool GameObject.isInvisible = GameObject + 0x3D8
+
+use crate::{
+    cocos2d::{make_cocos_wrapper, make_virtual_wrapper},
+    geometry::{OrientedBox, Rect},
+    impl_addr_funcs, impl_get_set, read_mem, read_ptr, GameObjectType, Ptr,
+};
+
+/// Maximum number of groups an object can be in.
+pub const MAX_GROUPS: usize = 10;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
@@ -12,8 +39,6 @@ impl GameObject {
         Self { address }
     }
 
-    // https://github.com/maxnut/gd.h/blob/436af2c6440a7efd1ba4720e48429fddaaf298e4/sprite_nodes/GameObject.h#L62-L194
-
     impl_get_set!(is_object_rect_dirty, set_is_object_rect_dirty, bool, 0x2C8);
     impl_get_set!(
         is_oriented_rect_dirty,
@@ -28,6 +53,108 @@ impl GameObject {
         bool,
         0x2CB
     );
+
+    impl_get_set!(x, set_x, f32, 0x34);
+    impl_get_set!(y, set_y, f32, 0x38);
+    impl_get_set!(rotation_x, set_rotation_x, f32, 0x20);
+    impl_get_set!(rotation_y, set_rotation_y, f32, 0x24);
+    impl_get_set!(scale_x, set_scale_x, f32, 0x28);
+    impl_get_set!(scale_y, set_scale_y, f32, 0x2C);
+    impl_get_set!(object_id, set_object_id, i32, 0x360);
+    impl_get_set!(object_radius, set_object_radius, f32, 0x2B4);
+    impl_get_set!(object_type_raw, set_object_type_raw, i32, 0x31C);
+    impl_get_set!(main_color_id, set_main_color_id, i32, 0x3C4);
+    impl_get_set!(secondary_color_id, set_secondary_color_id, i32, 0x3C8);
+    impl_get_set!(editor_layer, set_editor_layer, i32, 0x3CC);
+    impl_get_set!(editor_layer2, set_editor_layer2, i32, 0x3D0);
+    impl_get_set!(is_invisible, set_is_invisible, bool, 0x3D8);
+    impl_get_set!(start_x, set_start_x, f32, 0x32C);
+    impl_get_set!(start_y, set_start_y, f32, 0x330);
+
+    make_cocos_wrapper!(
+        "?isFlipX@CCSprite@cocos2d@@QAE_NXZ",
+        "Returns whether the object is flipped horizontally.",
+        pub is_flip_x() -> bool
+    );
+    make_cocos_wrapper!(
+        "?isFlipY@CCSprite@cocos2d@@QAE_NXZ",
+        "Returns whether the object is flipped vertically.",
+        pub is_flip_y() -> bool
+    );
+    make_virtual_wrapper!(
+        "?setFlipX@CCSprite@cocos2d@@UAEX_N@Z",
+        "Flips the object horizontally.",
+        pub set_flip_x(flip: bool)
+    );
+    make_virtual_wrapper!(
+        "?setFlipY@CCSprite@cocos2d@@UAEX_N@Z",
+        "Flips the object vertically.",
+        pub set_flip_y(flip: bool)
+    );
+
+    /// Get the X and Y position.
+    #[inline]
+    pub fn position(&self) -> (f32, f32) {
+        (self.x(), self.y())
+    }
+
+    /// Position of the object as placed in the editor, before any move triggers.
+    #[inline]
+    pub fn real_position(&self) -> (f32, f32) {
+        (self.start_x(), self.start_y())
+    }
+
+    /// Returns the rotation in degrees, clockwise.
+    #[inline]
+    pub fn rotation(&self) -> f32 {
+        self.rotation_x()
+    }
+
+    /// Returns the hitbox of the object in level coordinates.
+    ///
+    /// This is the rectangle the game cached on its last collision check, it
+    /// is stale while [`GameObject::is_object_rect_dirty`] is true.
+    #[inline]
+    pub fn object_rect(&self) -> Rect {
+        unsafe { *read_mem::<Rect>(self.address + 0x2B8) }
+    }
+
+    /// Returns the hitbox of the object rotated like the object, for drawing it.
+    #[inline]
+    pub fn oriented_box(&self) -> OrientedBox {
+        OrientedBox::from_object_rect(self.object_rect(), self.rotation())
+    }
+
+    /// Returns the object type, `None` if it isn't a known one.
+    #[inline]
+    pub fn object_type(&self) -> Option<GameObjectType> {
+        GameObjectType::from_raw(self.object_type_raw())
+    }
+
+    /// Sets the object type.
+    #[inline]
+    pub fn set_object_type(&self, object_type: GameObjectType) {
+        self.set_object_type_raw(object_type.raw())
+    }
+
+    /// Returns the group IDs the object is in.
+    pub fn groups(&self) -> Vec<i16> {
+        unsafe {
+            let groups = read_ptr(self.address + 0x384) as *const i16;
+            if groups.is_null() {
+                return Vec::new();
+            }
+            let count = (*read_mem::<i16>(self.address + 0x3C2)).clamp(0, MAX_GROUPS as i16);
+            std::slice::from_raw_parts(groups, count as usize).to_vec()
+        }
+    }
+
+    /// Returns whether the object is in a group.
+    #[inline]
+    pub fn is_in_group(&self, group: i16) -> bool {
+        self.groups().contains(&group)
+    }
 }
 
 impl_addr_funcs!(GameObject);
+crate::rtti::impl_rtti_class!(GameObject, "GameObject");
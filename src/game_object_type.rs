@@ -0,0 +1,181 @@
+/// Type of a level object, decides how the player interacts with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum GameObjectType {
+    Solid = 0,
+    Hazard = 2,
+    InverseGravityPortal = 3,
+    NormalGravityPortal = 4,
+    ShipPortal = 5,
+    CubePortal = 6,
+    Decoration = 7,
+    YellowJumpPad = 8,
+    PinkJumpPad = 9,
+    GravityPad = 10,
+    YellowJumpRing = 11,
+    PinkJumpRing = 12,
+    GravityRing = 13,
+    InverseMirrorPortal = 14,
+    NormalMirrorPortal = 15,
+    BallPortal = 16,
+    RegularSizePortal = 17,
+    MiniSizePortal = 18,
+    UfoPortal = 19,
+    Modifier = 20,
+    SecretCoin = 22,
+    DualPortal = 23,
+    SoloPortal = 24,
+    Slope = 25,
+    WavePortal = 26,
+    RobotPortal = 27,
+    TeleportPortal = 28,
+    GreenRing = 29,
+    Collectible = 30,
+    UserCoin = 31,
+    DropRing = 32,
+    SpiderPortal = 33,
+    RedJumpPad = 34,
+    RedJumpRing = 35,
+    CustomRing = 36,
+    DashRing = 37,
+    GravityDashRing = 38,
+    CollisionObject = 39,
+    Special = 40,
+}
+
+impl GameObjectType {
+    /// Converts the value stored in the game, `None` if it's unknown.
+    pub const fn from_raw(value: i32) -> Option<Self> {
+        use GameObjectType::*;
+        Some(match value {
+            0 => Solid,
+            2 => Hazard,
+            3 => InverseGravityPortal,
+            4 => NormalGravityPortal,
+            5 => ShipPortal,
+            6 => CubePortal,
+            7 => Decoration,
+            8 => YellowJumpPad,
+            9 => PinkJumpPad,
+            10 => GravityPad,
+            11 => YellowJumpRing,
+            12 => PinkJumpRing,
+            13 => GravityRing,
+            14 => InverseMirrorPortal,
+            15 => NormalMirrorPortal,
+            16 => BallPortal,
+            17 => RegularSizePortal,
+            18 => MiniSizePortal,
+            19 => UfoPortal,
+            20 => Modifier,
+            22 => SecretCoin,
+            23 => DualPortal,
+            24 => SoloPortal,
+            25 => Slope,
+            26 => WavePortal,
+            27 => RobotPortal,
+            28 => TeleportPortal,
+            29 => GreenRing,
+            30 => Collectible,
+            31 => UserCoin,
+            32 => DropRing,
+            33 => SpiderPortal,
+            34 => RedJumpPad,
+            35 => RedJumpRing,
+            36 => CustomRing,
+            37 => DashRing,
+            38 => GravityDashRing,
+            39 => CollisionObject,
+            40 => Special,
+            _ => return None,
+        })
+    }
+
+    /// Returns the value stored in the game.
+    #[inline]
+    pub const fn raw(self) -> i32 {
+        self as i32
+    }
+
+    /// Returns whether the object is a portal.
+    pub const fn is_portal(self) -> bool {
+        use GameObjectType::*;
+        matches!(
+            self,
+            InverseGravityPortal
+                | NormalGravityPortal
+                | ShipPortal
+                | CubePortal
+                | InverseMirrorPortal
+                | NormalMirrorPortal
+                | BallPortal
+                | RegularSizePortal
+                | MiniSizePortal
+                | UfoPortal
+                | DualPortal
+                | SoloPortal
+                | WavePortal
+                | RobotPortal
+                | TeleportPortal
+                | SpiderPortal
+        )
+    }
+
+    /// Returns whether the object is a jump pad.
+    pub const fn is_pad(self) -> bool {
+        use GameObjectType::*;
+        matches!(self, YellowJumpPad | PinkJumpPad | GravityPad | RedJumpPad)
+    }
+
+    /// Returns whether the object is an orb (ring) the player can click on.
+    pub const fn is_ring(self) -> bool {
+        use GameObjectType::*;
+        matches!(
+            self,
+            YellowJumpRing
+                | PinkJumpRing
+                | GravityRing
+                | GreenRing
+                | DropRing
+                | RedJumpRing
+                | CustomRing
+                | DashRing
+                | GravityDashRing
+        )
+    }
+
+    /// Returns whether touching the object kills the player.
+    #[inline]
+    pub const fn is_hazard(self) -> bool {
+        matches!(self, Self::Hazard)
+    }
+
+    /// Returns whether the player collides with the object as a block.
+    #[inline]
+    pub const fn is_solid(self) -> bool {
+        matches!(self, Self::Solid | Self::Slope)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_round_trip() {
+        let known: Vec<_> = (-1..=50).filter_map(GameObjectType::from_raw).collect();
+        assert_eq!(known.len(), 39);
+        for object_type in known {
+            assert_eq!(
+                GameObjectType::from_raw(object_type.raw()),
+                Some(object_type)
+            );
+        }
+        assert_eq!(GameObjectType::from_raw(1), None);
+        assert_eq!(GameObjectType::from_raw(21), None);
+        assert_eq!(GameObjectType::from_raw(2), Some(GameObjectType::Hazard));
+        assert!(GameObjectType::SpiderPortal.is_portal());
+        assert!(GameObjectType::RedJumpPad.is_pad());
+        assert!(!GameObjectType::Hazard.is_ring());
+    }
+}
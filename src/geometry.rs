@@ -0,0 +1,179 @@
+//! Rectangles and oriented boxes in level coordinates.
+
+/// An axis-aligned rectangle. `(x, y)` is the bottom left corner.
+///
+/// Same layout as `cocos2d::CCRect`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    pub const fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Creates a rectangle of the given size around `center`.
+    pub fn from_center(center: (f32, f32), width: f32, height: f32) -> Self {
+        Self::new(
+            center.0 - width / 2.0,
+            center.1 - height / 2.0,
+            width,
+            height,
+        )
+    }
+
+    #[inline]
+    pub fn center(&self) -> (f32, f32) {
+        (self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+
+    #[inline]
+    pub fn max_x(&self) -> f32 {
+        self.x + self.width
+    }
+
+    #[inline]
+    pub fn max_y(&self) -> f32 {
+        self.y + self.height
+    }
+
+    /// Returns whether the point is inside the rectangle (edges included).
+    pub fn contains(&self, point: (f32, f32)) -> bool {
+        point.0 >= self.x && point.0 <= self.max_x() && point.1 >= self.y && point.1 <= self.max_y()
+    }
+
+    /// Returns whether the two rectangles overlap (touching edges count).
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.x <= other.max_x()
+            && other.x <= self.max_x()
+            && self.y <= other.max_y()
+            && other.y <= self.max_y()
+    }
+
+    /// Returns the corners, counter-clockwise from the bottom left.
+    pub fn corners(&self) -> [(f32, f32); 4] {
+        [
+            (self.x, self.y),
+            (self.max_x(), self.y),
+            (self.max_x(), self.max_y()),
+            (self.x, self.max_y()),
+        ]
+    }
+}
+
+/// A rectangle rotated around its center.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct OrientedBox {
+    pub center: (f32, f32),
+    pub width: f32,
+    pub height: f32,
+    /// Clockwise rotation in degrees, like cocos2d uses.
+    pub rotation: f32,
+}
+
+impl OrientedBox {
+    pub const fn new(center: (f32, f32), width: f32, height: f32, rotation: f32) -> Self {
+        Self {
+            center,
+            width,
+            height,
+            rotation,
+        }
+    }
+
+    /// Builds the box of an object from its axis-aligned hitbox.
+    ///
+    /// GD only rotates object rectangles by multiples of 90 degrees (swapping
+    /// the width and height), so the size is swapped back before applying the
+    /// full rotation.
+    pub fn from_object_rect(rect: Rect, rotation: f32) -> Self {
+        let quarter_turns = (rotation / 90.0).round() as i64;
+        let (width, height) = if quarter_turns.rem_euclid(2) == 1 {
+            (rect.height, rect.width)
+        } else {
+            (rect.width, rect.height)
+        };
+        Self::new(rect.center(), width, height, rotation)
+    }
+
+    /// Returns the corners, counter-clockwise from the bottom left before rotating.
+    pub fn corners(&self) -> [(f32, f32); 4] {
+        // clockwise in a y-up coordinate system
+        let (sin, cos) = (-self.rotation.to_radians()).sin_cos();
+        let (hw, hh) = (self.width / 2.0, self.height / 2.0);
+        [(-hw, -hh), (hw, -hh), (hw, hh), (-hw, hh)].map(|(x, y)| {
+            (
+                self.center.0 + x * cos - y * sin,
+                self.center.1 + x * sin + y * cos,
+            )
+        })
+    }
+
+    /// Returns the smallest axis-aligned rectangle that contains the box.
+    pub fn bounding_rect(&self) -> Rect {
+        let corners = self.corners();
+        let (mut min, mut max) = (corners[0], corners[0]);
+        for (x, y) in &corners[1..] {
+            min = (min.0.min(*x), min.1.min(*y));
+            max = (max.0.max(*x), max.1.max(*y));
+        }
+        Rect::new(min.0, min.1, max.0 - min.0, max.1 - min.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: (f32, f32), b: (f32, f32)) {
+        assert!(
+            (a.0 - b.0).abs() < 1e-4 && (a.1 - b.1).abs() < 1e-4,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn rect() {
+        let rect = Rect::from_center((15.0, 15.0), 30.0, 10.0);
+        assert_eq!(rect, Rect::new(0.0, 10.0, 30.0, 10.0));
+        assert!(rect.contains((30.0, 20.0)));
+        assert!(!rect.contains((30.1, 20.0)));
+        assert!(rect.intersects(&Rect::new(30.0, 0.0, 5.0, 10.0)));
+        assert!(!rect.intersects(&Rect::new(31.0, 0.0, 5.0, 10.0)));
+    }
+
+    #[test]
+    fn oriented_box_rotation() {
+        let obb = OrientedBox::new((0.0, 0.0), 4.0, 2.0, 90.0);
+        let corners = obb.corners();
+        // rotating clockwise by 90 degrees moves the bottom left corner to the top left
+        assert_close(corners[0], (-1.0, 2.0));
+        assert_close(corners[1], (-1.0, -2.0));
+        let bounds = obb.bounding_rect();
+        assert_close((bounds.width, bounds.height), (2.0, 4.0));
+    }
+
+    #[test]
+    fn oriented_box_from_object_rect() {
+        // a 30x10 object rotated by 90 degrees has a 10x30 object rect
+        let rect = Rect::from_center((100.0, 50.0), 10.0, 30.0);
+        let obb = OrientedBox::from_object_rect(rect, 90.0);
+        assert_eq!((obb.width, obb.height), (30.0, 10.0));
+        assert_close(obb.center, (100.0, 50.0));
+        let bounds = obb.bounding_rect();
+        assert_close((bounds.x, bounds.y), (rect.x, rect.y));
+
+        let obb = OrientedBox::from_object_rect(rect, -180.0);
+        assert_eq!((obb.width, obb.height), (10.0, 30.0));
+    }
+}
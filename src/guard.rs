@@ -0,0 +1,675 @@
+//! Catching crashes in mod code.
+//!
+//! A panic can't unwind out of a hook into the game (the process aborts), and
+//! an access violation in mod code (e.g. a wrapper called on a freed object)
+//! takes the game down. [`catch`] runs a closure and turns both into a
+//! [`GuardError`]; [`call`] also writes a [`CrashReport`] and disables the mod.
+//!
+//! Every callback the crate calls from the game (the `modding::Mod`
+//! callbacks, hotkeys, main thread tasks) runs inside [`call`]. Hooks written
+//! by mods should do the same:
+//!
+//! ```ignore
+//! extern "fastcall" fn update(play_layer: Ptr, _edx: Ptr, dt: f32) {
+//!     guard::call("PlayLayer::update", || my_update(play_layer, dt));
+//!     original(play_layer, 0, dt)
+//! }
+//! ```
+//!
+//! Structured exceptions are caught by a vectored exception handler installed
+//! with `install`. When one happens in the mod's own code inside [`catch`] on
+//! the same thread, the handler saves the registers and the top of the stack
+//! and makes the faulting instruction call a function that panics, which
+//! unwinds back to [`catch`]. This only works "where possible": code between
+//! the fault and [`catch`] doesn't get to clean up, and stack overflows can't
+//! be caught.
+//!
+//! Faults in the game's code (e.g. in a function called through a wrapper on
+//! a freed object) are not recoverable: its frames can't be unwound through,
+//! and the game may handle the exception itself. The handler only saves a
+//! [`CrashReport`] for them and lets the exception go on as if it wasn't
+//! installed.
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt, fs, io,
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::Ptr;
+
+pub const EXCEPTION_ACCESS_VIOLATION: u32 = 0xC0000005;
+pub const EXCEPTION_IN_PAGE_ERROR: u32 = 0xC0000006;
+pub const EXCEPTION_ILLEGAL_INSTRUCTION: u32 = 0xC000001D;
+pub const EXCEPTION_ARRAY_BOUNDS_EXCEEDED: u32 = 0xC000008C;
+pub const EXCEPTION_INT_DIVIDE_BY_ZERO: u32 = 0xC0000094;
+pub const EXCEPTION_INT_OVERFLOW: u32 = 0xC0000095;
+pub const EXCEPTION_PRIV_INSTRUCTION: u32 = 0xC0000096;
+pub const EXCEPTION_STACK_OVERFLOW: u32 = 0xC00000FD;
+
+/// A structured exception raised by the CPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Exception {
+    /// `EXCEPTION_*` code.
+    pub code: u32,
+    /// Address of the faulting instruction.
+    pub address: Ptr,
+    /// For access violations: whether it was a write and the accessed address.
+    pub access: Option<(bool, Ptr)>,
+}
+
+impl Exception {
+    /// Returns whether the exception can be turned into a [`GuardError`].
+    pub fn is_recoverable(code: u32) -> bool {
+        matches!(
+            code,
+            EXCEPTION_ACCESS_VIOLATION
+                | EXCEPTION_IN_PAGE_ERROR
+                | EXCEPTION_ILLEGAL_INSTRUCTION
+                | EXCEPTION_ARRAY_BOUNDS_EXCEEDED
+                | EXCEPTION_INT_DIVIDE_BY_ZERO
+                | EXCEPTION_INT_OVERFLOW
+                | EXCEPTION_PRIV_INSTRUCTION
+        )
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self.code {
+            EXCEPTION_ACCESS_VIOLATION => "access violation",
+            EXCEPTION_IN_PAGE_ERROR => "in-page error",
+            EXCEPTION_ILLEGAL_INSTRUCTION => "illegal instruction",
+            EXCEPTION_ARRAY_BOUNDS_EXCEEDED => "array bounds exceeded",
+            EXCEPTION_INT_DIVIDE_BY_ZERO => "integer division by zero",
+            EXCEPTION_INT_OVERFLOW => "integer overflow",
+            EXCEPTION_PRIV_INSTRUCTION => "privileged instruction",
+            EXCEPTION_STACK_OVERFLOW => "stack overflow",
+            _ => "exception",
+        }
+    }
+}
+
+impl fmt::Display for Exception {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({:#010X}) at {:#010X}",
+            self.name(),
+            self.code,
+            self.address
+        )?;
+        if let Some((write, address)) = self.access {
+            let access = if write { "writing" } else { "reading" };
+            write!(f, " {access} {address:#010X}")?;
+        }
+        Ok(())
+    }
+}
+
+/// What went wrong in a guarded call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuardError {
+    /// The closure panicked with this message.
+    Panic(String),
+    /// The closure raised a structured exception.
+    Exception(Exception),
+}
+
+impl fmt::Display for GuardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Panic(message) => write!(f, "panicked: {message}"),
+            Self::Exception(exception) => exception.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for GuardError {}
+
+/// The general purpose registers of a thread (32-bit).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Registers {
+    pub eax: u32,
+    pub ebx: u32,
+    pub ecx: u32,
+    pub edx: u32,
+    pub esi: u32,
+    pub edi: u32,
+    pub ebp: u32,
+    pub esp: u32,
+    pub eip: u32,
+    pub eflags: u32,
+}
+
+impl fmt::Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "eax={:08X} ebx={:08X} ecx={:08X} edx={:08X} esi={:08X} edi={:08X}",
+            self.eax, self.ebx, self.ecx, self.edx, self.esi, self.edi
+        )?;
+        write!(
+            f,
+            "ebp={:08X} esp={:08X} eip={:08X} eflags={:08X}",
+            self.ebp, self.esp, self.eip, self.eflags
+        )
+    }
+}
+
+/// State of the level being played when a crash happened.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct GameState {
+    /// `PlayLayer::time`.
+    pub time: f64,
+    /// Attempt number of the level.
+    pub attempt: i32,
+    pub is_dead: bool,
+    pub is_practice_mode: bool,
+    /// x position of player 1.
+    pub x: f32,
+}
+
+/// Everything known about a crash, see [`call`].
+#[derive(Debug, Clone)]
+pub struct CrashReport {
+    /// When the crash happened.
+    pub time: SystemTime,
+    /// Name of the mod that crashed, if it was built with `#[geometrydash::main]`.
+    pub mod_name: Option<String>,
+    /// Where the crash was caught, e.g. `Mod::on_tick`.
+    pub context: String,
+    pub error: GuardError,
+    /// Where the panic happened.
+    pub location: Option<String>,
+    /// Registers at the faulting instruction, only for exceptions.
+    pub registers: Option<Registers>,
+    /// Words at the top of the stack, only for exceptions.
+    pub stack: Vec<u32>,
+    /// `None` outside of levels.
+    pub game_state: Option<GameState>,
+    pub backtrace: String,
+}
+
+impl fmt::Display for CrashReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "crash at {}", crate::logging::timestamp(self.time))?;
+        if let Some(mod_name) = &self.mod_name {
+            writeln!(f, "mod: {mod_name}")?;
+        }
+        writeln!(
+            f,
+            "geometrydash {}, Geometry Dash {}",
+            env!("CARGO_PKG_VERSION"),
+            crate::GAME_VERSION
+        )?;
+        writeln!(f, "in {}: {}", self.context, self.error)?;
+        if let Some(location) = &self.location {
+            writeln!(f, "at {location}")?;
+        }
+        if let Some(registers) = &self.registers {
+            writeln!(f, "\nregisters:\n{registers}")?;
+        }
+        if !self.stack.is_empty() {
+            writeln!(f, "\nstack:")?;
+            let esp = self.registers.map_or(0, |r| r.esp);
+            for (row, words) in self.stack.chunks(4).enumerate() {
+                write!(f, "{:08X}:", esp.wrapping_add(row as u32 * 16))?;
+                for word in words {
+                    write!(f, " {word:08X}")?;
+                }
+                writeln!(f)?;
+            }
+        }
+        match &self.game_state {
+            Some(state) => writeln!(
+                f,
+                "\nlevel: time {:.3}, attempt {}, x {:.1}, dead {}, practice {}",
+                state.time, state.attempt, state.x, state.is_dead, state.is_practice_mode
+            )?,
+            None => writeln!(f, "\nnot in a level")?,
+        }
+        if !self.backtrace.is_empty() {
+            writeln!(f, "\nbacktrace:\n{}", self.backtrace)?;
+        }
+        Ok(())
+    }
+}
+
+impl CrashReport {
+    /// Name of the report file, `crash-<date>_<time>.txt`.
+    pub fn file_name(&self) -> String {
+        let time = crate::logging::timestamp(self.time)
+            .replace(' ', "_")
+            .replace(':', "-");
+        format!("crash-{time}.txt")
+    }
+
+    /// Writes the report to a file in `dir` and returns its path.
+    pub fn save(&self, dir: &Path) -> io::Result<PathBuf> {
+        fs::create_dir_all(dir)?;
+        let path = dir.join(self.file_name());
+        fs::write(&path, self.to_string())?;
+        Ok(path)
+    }
+}
+
+/// What a fault left behind for [`catch`], on the thread that faulted.
+#[derive(Debug, Default)]
+struct Details {
+    location: Option<String>,
+    registers: Option<Registers>,
+    stack: Vec<u32>,
+    backtrace: String,
+}
+
+thread_local! {
+    /// How many [`catch`] calls the thread is in.
+    static DEPTH: Cell<u32> = const { Cell::new(0) };
+    static DETAILS: RefCell<Option<Details>> = const { RefCell::new(None) };
+}
+
+/// Returns whether the current thread is inside [`catch`].
+pub fn is_guarded() -> bool {
+    DEPTH.with(Cell::get) > 0
+}
+
+/// Returns the message of a panic payload.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".to_string())
+}
+
+/// Runs a closure, turning a panic (or a structured exception, once `install`
+/// was called) into an error.
+///
+/// The details of an error are kept for [`report`]; call [`discard_details`]
+/// when handling it without a report.
+pub fn catch<R, F: FnOnce() -> R>(f: F) -> Result<R, GuardError> {
+    DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let result = catch_unwind(AssertUnwindSafe(f));
+    DEPTH.with(|depth| depth.set(depth.get() - 1));
+    match result {
+        Ok(value) => {
+            // left by a failed call inside `f` that wasn't reported
+            discard_details();
+            Ok(value)
+        }
+        Err(payload) => Err(match payload.downcast::<Exception>() {
+            Ok(exception) => GuardError::Exception(*exception),
+            Err(payload) => GuardError::Panic(panic_message(&*payload)),
+        }),
+    }
+}
+
+/// Forgets the details of the last error [`catch`] returned on this thread.
+pub fn discard_details() {
+    DETAILS.with(|details| details.take());
+}
+
+/// Builds the report of an error returned by [`catch`] on this thread.
+pub fn report(context: &str, error: GuardError) -> CrashReport {
+    let details = DETAILS.with(|details| details.take()).unwrap_or_default();
+    CrashReport {
+        time: SystemTime::now(),
+        mod_name: mod_name(),
+        context: context.to_string(),
+        error,
+        location: details.location,
+        registers: details.registers,
+        stack: details.stack,
+        game_state: game_state(),
+        backtrace: details.backtrace,
+    }
+}
+
+/// Runs a closure like [`catch`]. If it fails, logs the error, saves a
+/// [`CrashReport`] next to the logs and disables the mod.
+pub fn call<R, F: FnOnce() -> R>(context: &str, f: F) -> Option<R> {
+    match catch(f) {
+        Ok(value) => Some(value),
+        Err(error) => {
+            let report = report(context, error);
+            match crate::logging::log_dir().map(|dir| report.save(&dir)) {
+                Some(Ok(path)) => log::error!(
+                    "{} crashed in {context}: {}, report saved to {}",
+                    report.mod_name.as_deref().unwrap_or("mod"),
+                    report.error,
+                    path.display()
+                ),
+                _ => log::error!("{report}"),
+            }
+            disable_mod();
+            None
+        }
+    }
+}
+
+/// Installs the panic hook that adds the location and backtrace of panics to
+/// the reports, calling the previous hook after. Set up the logger first.
+///
+/// The mod runtime calls it once `Mod::on_load` returned, mods don't need to.
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if is_guarded() {
+            let details = Details {
+                location: info.location().map(|l| l.to_string()),
+                backtrace: std::backtrace::Backtrace::force_capture().to_string(),
+                ..Default::default()
+            };
+            DETAILS.with(|d| *d.borrow_mut() = Some(details));
+        }
+        previous(info);
+    }));
+}
+
+#[cfg(game_bindings)]
+fn mod_name() -> Option<String> {
+    crate::modding::name()
+}
+
+#[cfg(not(game_bindings))]
+fn mod_name() -> Option<String> {
+    None
+}
+
+#[cfg(game_bindings)]
+fn game_state() -> Option<GameState> {
+    use crate::{AddressUtils, GameManager};
+
+    // the game may be what crashed, so don't crash again while reading it
+    catch(|| {
+        let play_layer = GameManager::shared().play_layer().to_option()?;
+        Some(GameState {
+            time: play_layer.time(),
+            attempt: play_layer.current_attempt(),
+            is_dead: play_layer.is_dead_raw(),
+            is_practice_mode: play_layer.is_practice_mode(),
+            x: play_layer.player1().x(),
+        })
+    })
+    .inspect_err(|_| discard_details())
+    .ok()
+    .flatten()
+}
+
+#[cfg(not(game_bindings))]
+fn game_state() -> Option<GameState> {
+    None
+}
+
+#[cfg(game_bindings)]
+fn disable_mod() {
+    crate::modding::disable();
+}
+
+#[cfg(not(game_bindings))]
+fn disable_mod() {}
+
+#[cfg(game_bindings)]
+mod seh {
+    use super::*;
+    use crate::{read_mem, read_ptr};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use windows::{
+        core::PCWSTR,
+        Win32::{
+            Foundation::HMODULE,
+            System::{
+                Diagnostics::Debug::{
+                    AddVectoredExceptionHandler, RemoveVectoredExceptionHandler, EXCEPTION_POINTERS,
+                },
+                LibraryLoader::{
+                    GetModuleHandleExW, GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS,
+                    GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT,
+                },
+            },
+        },
+    };
+
+    // x86 CONTEXT
+    // DWORD Edi = +0x9C
+    // DWORD Esi = +0xA0
+    // DWORD Ebx = +0xA4
+    // DWORD Edx = +0xA8
+    // DWORD Ecx = +0xAC
+    // DWORD Eax = +0xB0
+    // DWORD Ebp = +0xB4
+    // DWORD Eip = +0xB8
+    // DWORD EFlags = +0xC0
+    // DWORD Esp = +0xC4
+    const EIP: Ptr = 0xB8;
+    const ESP: Ptr = 0xC4;
+
+    /// How many words of the stack go in the report.
+    const STACK_WORDS: usize = 32;
+
+    const EXCEPTION_CONTINUE_EXECUTION: i32 = -1;
+    const EXCEPTION_CONTINUE_SEARCH: i32 = 0;
+
+    static HANDLER: AtomicUsize = AtomicUsize::new(0);
+    /// Start and end of the module this crate is linked into.
+    static IMAGE_START: AtomicUsize = AtomicUsize::new(0);
+    static IMAGE_END: AtomicUsize = AtomicUsize::new(0);
+
+    thread_local! {
+        static PENDING: Cell<Option<Exception>> = const { Cell::new(None) };
+    }
+
+    unsafe fn read_registers(context: Ptr) -> Registers {
+        let reg = |offset: Ptr| unsafe { *read_mem::<u32>(context + offset) };
+        Registers {
+            eax: reg(0xB0),
+            ebx: reg(0xA4),
+            ecx: reg(0xAC),
+            edx: reg(0xA8),
+            esi: reg(0xA0),
+            edi: reg(0x9C),
+            ebp: reg(0xB4),
+            esp: reg(ESP),
+            eip: reg(EIP),
+            eflags: reg(0xC0),
+        }
+    }
+
+    type Handler = unsafe extern "system" fn(*mut EXCEPTION_POINTERS) -> i32;
+
+    unsafe extern "system" fn handler(info: *mut EXCEPTION_POINTERS) -> i32 {
+        let record = &*(*info).ExceptionRecord;
+        let code = record.ExceptionCode.0 as u32;
+        if !is_guarded() || !Exception::is_recoverable(code) {
+            return EXCEPTION_CONTINUE_SEARCH;
+        }
+        let access =
+            (code == EXCEPTION_ACCESS_VIOLATION && record.NumberParameters >= 2).then(|| {
+                (
+                    record.ExceptionInformation[0] == 1,
+                    record.ExceptionInformation[1],
+                )
+            });
+        let exception = Exception {
+            code,
+            address: record.ExceptionAddress as Ptr,
+            access,
+        };
+        let context = (*info).ContextRecord as Ptr;
+        let registers = read_registers(context);
+        let stack =
+            std::slice::from_raw_parts(registers.esp as Ptr as *const u32, STACK_WORDS).to_vec();
+        let details = Details {
+            location: None,
+            registers: Some(registers),
+            stack,
+            backtrace: String::new(),
+        };
+
+        let image = IMAGE_START.load(Ordering::Relaxed)..IMAGE_END.load(Ordering::Relaxed);
+        if !image.contains(&exception.address) {
+            // unwinding through the game's frames is undefined behavior, and it
+            // may have a handler of its own for this
+            report_game_fault(exception, details);
+            return EXCEPTION_CONTINUE_SEARCH;
+        }
+        DETAILS.with(|d| *d.borrow_mut() = Some(details));
+        PENDING.with(|pending| pending.set(Some(exception)));
+
+        // make the faulting instruction call `raise`, as if it was a call instruction
+        let esp = registers.esp as Ptr - 4;
+        *read_mem::<u32>(esp) = registers.eip;
+        *read_mem::<u32>(context + ESP) = esp as u32;
+        *read_mem::<u32>(context + EIP) = raise as Raise as Ptr as u32;
+        EXCEPTION_CONTINUE_EXECUTION
+    }
+
+    /// Saves the report of a fault in the game's code, which [`catch`] can't
+    /// recover from.
+    fn report_game_fault(exception: Exception, details: Details) {
+        let report = CrashReport {
+            time: SystemTime::now(),
+            mod_name: mod_name(),
+            context: "game code".to_string(),
+            error: GuardError::Exception(exception),
+            location: None,
+            registers: details.registers,
+            stack: details.stack,
+            // reading the game's state could fault again
+            game_state: None,
+            backtrace: std::backtrace::Backtrace::force_capture().to_string(),
+        };
+        match crate::logging::log_dir().map(|dir| report.save(&dir)) {
+            Some(Ok(path)) => log::error!(
+                "{} in game code called by the mod, report saved to {}",
+                report.error,
+                path.display()
+            ),
+            _ => log::error!("{report}"),
+        }
+    }
+
+    type Raise = extern "C-unwind" fn() -> !;
+
+    extern "C-unwind" fn raise() -> ! {
+        let exception = PENDING.with(Cell::take).expect("no pending exception");
+        let backtrace = std::backtrace::Backtrace::force_capture().to_string();
+        DETAILS.with(|details| {
+            if let Some(details) = details.borrow_mut().as_mut() {
+                details.backtrace = backtrace;
+            }
+        });
+        std::panic::resume_unwind(Box::new(exception))
+    }
+
+    /// Adds the exception handler that lets [`catch`] catch structured
+    /// exceptions.
+    pub fn install() {
+        if HANDLER.load(Ordering::Relaxed) != 0 {
+            return;
+        }
+        let mut module = HMODULE::default();
+        let found = unsafe {
+            GetModuleHandleExW(
+                GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS
+                    | GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT,
+                PCWSTR(handler as Handler as Ptr as *const u16),
+                &mut module,
+            )
+        };
+        if found.is_ok() {
+            // u32 IMAGE_DOS_HEADER.e_lfanew = base + 0x3C
+            // u32 IMAGE_NT_HEADERS32.OptionalHeader.SizeOfImage = NT + 0x50
+            let base = module.0 as Ptr;
+            let size = unsafe { read_ptr(base + read_ptr(base + 0x3C) + 0x50) };
+            IMAGE_START.store(base, Ordering::Relaxed);
+            IMAGE_END.store(base + size, Ordering::Relaxed);
+        }
+        let handle = unsafe { AddVectoredExceptionHandler(1, Some(handler)) };
+        HANDLER.store(handle as Ptr, Ordering::Relaxed);
+    }
+
+    /// Removes the exception handler, it must be removed before the DLL is unloaded.
+    pub fn uninstall() {
+        let handle = HANDLER.swap(0, Ordering::Relaxed);
+        if handle != 0 {
+            unsafe { RemoveVectoredExceptionHandler(handle as _) };
+        }
+    }
+}
+
+#[cfg(game_bindings)]
+pub use seh::{install, uninstall};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catches_panics() {
+        assert_eq!(catch(|| 1), Ok(1));
+        assert!(!is_guarded());
+        assert_eq!(
+            catch(|| -> () { panic!("oops {}", 1) }),
+            Err(GuardError::Panic("oops 1".to_string()))
+        );
+        // nested calls
+        assert_eq!(
+            catch(|| {
+                assert!(is_guarded());
+                catch(|| panic!("inner")).unwrap_err()
+            }),
+            Ok(GuardError::Panic("inner".to_string()))
+        );
+        assert!(!is_guarded());
+
+        // details nobody reported don't end up in the next report
+        DETAILS.with(|details| *details.borrow_mut() = Some(Details::default()));
+        assert_eq!(catch(|| 1), Ok(1));
+        assert!(DETAILS.with(|details| details.borrow().is_none()));
+    }
+
+    #[test]
+    fn formats_reports() {
+        let exception = Exception {
+            code: EXCEPTION_ACCESS_VIOLATION,
+            address: 0x0060_1234,
+            access: Some((false, 0x10)),
+        };
+        assert_eq!(
+            exception.to_string(),
+            "access violation (0xC0000005) at 0x00601234 reading 0x00000010"
+        );
+        assert!(Exception::is_recoverable(exception.code));
+        assert!(!Exception::is_recoverable(EXCEPTION_STACK_OVERFLOW));
+
+        let report = CrashReport {
+            time: std::time::UNIX_EPOCH,
+            mod_name: Some("noclip".to_string()),
+            context: "Mod::on_tick".to_string(),
+            error: GuardError::Exception(exception),
+            location: None,
+            registers: Some(Registers {
+                esp: 0x0019_F000,
+                ..Default::default()
+            }),
+            stack: (0..6).collect(),
+            game_state: Some(GameState {
+                time: 12.5,
+                attempt: 3,
+                ..Default::default()
+            }),
+            backtrace: String::new(),
+        };
+        assert_eq!(report.file_name(), "crash-1970-01-01_00-00-00.000.txt");
+        let text = report.to_string();
+        assert!(text.contains("mod: noclip\n"));
+        assert!(text.contains("in Mod::on_tick: access violation"));
+        assert!(text.contains(
+            "0019F000: 00000000 00000001 00000002 00000003\n0019F010: 00000004 00000005\n"
+        ));
+        assert!(text.contains("level: time 12.500, attempt 3,"));
+    }
+}
@@ -0,0 +1,499 @@
+//! Hitbox overlay shapes.
+//!
+//! [`HitboxOverlay`] turns a [`HitboxFrame`] (the player and object hitboxes of
+//! one frame, in level coordinates) into colored [`Shape`]s and hands them to a
+//! [`HitboxSink`], which does the actual drawing. Generating the shapes doesn't
+//! touch the game, only [`HitboxFrame::capture`] does.
+
+use crate::{
+    geometry::{OrientedBox, Rect},
+    GameObjectType,
+};
+use std::collections::VecDeque;
+
+/// An RGBA color with components in `0.0..=1.0`. Same layout as `cocos2d::ccColor4F`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Returns the same color with another alpha.
+    pub const fn with_alpha(self, a: f32) -> Self {
+        Self { a, ..self }
+    }
+}
+
+/// What a shape is the hitbox of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HitboxKind {
+    /// The hitbox the player collides with blocks and hazards with.
+    PlayerOuter,
+    /// The small hitbox inside the player, which kills when it touches a block.
+    PlayerInner,
+    /// A past player hitbox, shown after dying.
+    Trail,
+    Solid,
+    Hazard,
+    /// Portals, pads, rings and collectibles.
+    Interactable,
+}
+
+impl HitboxKind {
+    /// Returns the kind of hitbox an object has, `None` for decoration.
+    pub fn for_object(object_type: GameObjectType) -> Option<Self> {
+        if object_type.is_solid() {
+            Some(Self::Solid)
+        } else if object_type.is_hazard() {
+            Some(Self::Hazard)
+        } else if object_type.is_portal()
+            || object_type.is_pad()
+            || object_type.is_ring()
+            || object_type == GameObjectType::Collectible
+            || object_type == GameObjectType::UserCoin
+            || object_type == GameObjectType::SecretCoin
+        {
+            Some(Self::Interactable)
+        } else {
+            None
+        }
+    }
+}
+
+/// The outline of a shape.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Geometry {
+    /// Corners of a (possibly rotated) rectangle, counter-clockwise.
+    Quad([(f32, f32); 4]),
+    Circle {
+        center: (f32, f32),
+        radius: f32,
+    },
+}
+
+/// A colored shape in level coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shape {
+    pub kind: HitboxKind,
+    pub geometry: Geometry,
+    pub fill: Color,
+    pub outline: Color,
+}
+
+/// Receives the shapes of a frame, e.g. to draw them.
+pub trait HitboxSink {
+    /// Called before the shapes of a frame, e.g. to clear the last one.
+    fn begin(&mut self) {}
+
+    fn shape(&mut self, shape: &Shape);
+
+    /// Called after the last shape of a frame.
+    fn end(&mut self) {}
+}
+
+/// Collects the shapes, replacing the ones of the previous frame.
+impl HitboxSink for Vec<Shape> {
+    fn begin(&mut self) {
+        self.clear();
+    }
+
+    fn shape(&mut self, shape: &Shape) {
+        self.push(*shape);
+    }
+}
+
+/// The hitbox of a player.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PlayerHitbox {
+    /// The axis-aligned object rect of the player.
+    pub rect: Rect,
+    /// Rotation in degrees, clockwise.
+    pub rotation: f32,
+}
+
+impl PlayerHitbox {
+    /// Size of the inner hitbox relative to the outer one.
+    pub const INNER_SCALE: f32 = 0.3;
+
+    /// Returns the inner hitbox, which isn't rotated.
+    pub fn inner(&self) -> Rect {
+        Rect::from_center(
+            self.rect.center(),
+            self.rect.width * Self::INNER_SCALE,
+            self.rect.height * Self::INNER_SCALE,
+        )
+    }
+}
+
+/// The hitbox of an object.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObjectHitbox {
+    pub kind: HitboxKind,
+    /// The axis-aligned object rect.
+    pub rect: Rect,
+    /// Rotation in degrees, clockwise.
+    pub rotation: f32,
+    /// Radius of circular hitboxes (saws), `0.0` for rectangles.
+    pub radius: f32,
+}
+
+/// Everything an overlay draws for one frame.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HitboxFrame {
+    pub players: Vec<PlayerHitbox>,
+    pub objects: Vec<ObjectHitbox>,
+    pub is_dead: bool,
+}
+
+/// Which hitboxes to show and how.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HitboxSettings {
+    pub show_player: bool,
+    pub show_inner: bool,
+    pub show_solids: bool,
+    pub show_hazards: bool,
+    pub show_interactables: bool,
+    /// Shows the last player hitboxes after dying.
+    pub show_trail_on_death: bool,
+    /// Number of frames kept for the trail.
+    pub trail_length: usize,
+    pub player_color: Color,
+    pub inner_color: Color,
+    pub trail_color: Color,
+    pub solid_color: Color,
+    pub hazard_color: Color,
+    pub interactable_color: Color,
+    /// Alpha of the fill, the outline uses the color as-is.
+    pub fill_alpha: f32,
+}
+
+impl Default for HitboxSettings {
+    fn default() -> Self {
+        Self {
+            show_player: true,
+            show_inner: true,
+            show_solids: true,
+            show_hazards: true,
+            show_interactables: true,
+            show_trail_on_death: true,
+            trail_length: 240,
+            player_color: Color::new(1.0, 0.0, 0.0, 1.0),
+            inner_color: Color::new(0.0, 0.0, 1.0, 1.0),
+            trail_color: Color::new(1.0, 0.5, 0.0, 0.5),
+            solid_color: Color::new(0.0, 0.25, 1.0, 1.0),
+            hazard_color: Color::new(1.0, 0.0, 0.0, 1.0),
+            interactable_color: Color::new(0.0, 1.0, 0.0, 1.0),
+            fill_alpha: 0.25,
+        }
+    }
+}
+
+impl HitboxSettings {
+    /// Returns whether hitboxes of this kind are shown.
+    pub fn shows(&self, kind: HitboxKind) -> bool {
+        match kind {
+            HitboxKind::PlayerOuter => self.show_player,
+            HitboxKind::PlayerInner => self.show_player && self.show_inner,
+            HitboxKind::Trail => self.show_player && self.show_trail_on_death,
+            HitboxKind::Solid => self.show_solids,
+            HitboxKind::Hazard => self.show_hazards,
+            HitboxKind::Interactable => self.show_interactables,
+        }
+    }
+
+    /// Returns the outline color of a kind of hitbox.
+    pub fn color(&self, kind: HitboxKind) -> Color {
+        match kind {
+            HitboxKind::PlayerOuter => self.player_color,
+            HitboxKind::PlayerInner => self.inner_color,
+            HitboxKind::Trail => self.trail_color,
+            HitboxKind::Solid => self.solid_color,
+            HitboxKind::Hazard => self.hazard_color,
+            HitboxKind::Interactable => self.interactable_color,
+        }
+    }
+}
+
+/// Generates the hitbox shapes of each frame and keeps the player trail.
+#[derive(Debug, Clone, Default)]
+pub struct HitboxOverlay {
+    pub settings: HitboxSettings,
+    trail: VecDeque<Vec<PlayerHitbox>>,
+}
+
+impl HitboxOverlay {
+    pub fn new(settings: HitboxSettings) -> Self {
+        Self {
+            settings,
+            trail: VecDeque::new(),
+        }
+    }
+
+    /// Records the player hitboxes of a frame for the trail.
+    ///
+    /// Nothing is recorded once the player is dead, so the trail ends where they died.
+    pub fn record(&mut self, frame: &HitboxFrame) {
+        if frame.is_dead || self.settings.trail_length == 0 {
+            return;
+        }
+        while self.trail.len() >= self.settings.trail_length {
+            self.trail.pop_front();
+        }
+        self.trail.push_back(frame.players.clone());
+    }
+
+    /// Forgets the trail, e.g. when the level restarts.
+    pub fn clear_trail(&mut self) {
+        self.trail.clear();
+    }
+
+    /// Number of frames in the trail.
+    pub fn trail_len(&self) -> usize {
+        self.trail.len()
+    }
+
+    /// Returns the shapes of a frame, from back to front.
+    pub fn shapes(&self, frame: &HitboxFrame) -> Vec<Shape> {
+        let mut shapes = Vec::new();
+        self.draw(frame, &mut shapes);
+        shapes
+    }
+
+    /// Sends the shapes of a frame to `sink`, from back to front.
+    pub fn draw<S: HitboxSink + ?Sized>(&self, frame: &HitboxFrame, sink: &mut S) {
+        sink.begin();
+        for object in &frame.objects {
+            if !self.settings.shows(object.kind) {
+                continue;
+            }
+            let geometry = if object.radius > 0.0 {
+                Geometry::Circle {
+                    center: object.rect.center(),
+                    radius: object.radius,
+                }
+            } else {
+                Geometry::Quad(
+                    OrientedBox::from_object_rect(object.rect, object.rotation).corners(),
+                )
+            };
+            sink.shape(&self.shape(object.kind, geometry));
+        }
+        if frame.is_dead && self.settings.shows(HitboxKind::Trail) {
+            for player in self.trail.iter().flatten() {
+                let geometry = Geometry::Quad(player.rect.corners());
+                sink.shape(&self.shape(HitboxKind::Trail, geometry));
+            }
+        }
+        for player in &frame.players {
+            if self.settings.shows(HitboxKind::PlayerOuter) {
+                let geometry = Geometry::Quad(
+                    OrientedBox::new(
+                        player.rect.center(),
+                        player.rect.width,
+                        player.rect.height,
+                        player.rotation,
+                    )
+                    .corners(),
+                );
+                sink.shape(&self.shape(HitboxKind::PlayerOuter, geometry));
+            }
+            if self.settings.shows(HitboxKind::PlayerInner) {
+                let geometry = Geometry::Quad(player.inner().corners());
+                sink.shape(&self.shape(HitboxKind::PlayerInner, geometry));
+            }
+        }
+        sink.end();
+    }
+
+    fn shape(&self, kind: HitboxKind, geometry: Geometry) -> Shape {
+        let outline = self.settings.color(kind);
+        Shape {
+            kind,
+            geometry,
+            fill: outline.with_alpha(outline.a * self.settings.fill_alpha),
+            outline,
+        }
+    }
+}
+
+#[cfg(game_bindings)]
+impl HitboxFrame {
+    /// Captures the hitboxes of the players and of the objects between
+    /// `camera_x` and `camera_x + view_width`.
+    pub fn capture(play_layer: crate::PlayLayer, view_width: f32) -> Self {
+        use crate::{AddressUtils, GameObject, PlayLayer};
+
+        let mut players = vec![play_layer.player1()];
+        if let Some(player2) = play_layer.player2().to_option() {
+            players.push(player2);
+        }
+        let players = players
+            .into_iter()
+            .filter(|p| !p.is_null())
+            .map(|p| {
+                let object = GameObject::from_address(p.ptr());
+                PlayerHitbox {
+                    rect: object.object_rect(),
+                    rotation: p.rotation_x(),
+                }
+            })
+            .collect();
+
+        let start = play_layer.camera_x() - PlayLayer::SECTION_WIDTH;
+        let sections = PlayLayer::section_for_x(start.max(0.0))
+            ..=PlayLayer::section_for_x(play_layer.camera_x() + view_width);
+        let objects = sections
+            .flat_map(|section| {
+                play_layer.objects_in_section(section as f32 * PlayLayer::SECTION_WIDTH)
+            })
+            .filter(|o| !o.is_invisible())
+            .filter_map(|o| {
+                let kind = HitboxKind::for_object(o.object_type()?)?;
+                Some(ObjectHitbox {
+                    kind,
+                    rect: o.object_rect(),
+                    rotation: o.rotation(),
+                    radius: if kind == HitboxKind::Hazard {
+                        o.object_radius()
+                    } else {
+                        0.0
+                    },
+                })
+            })
+            .collect();
+
+        Self {
+            players,
+            objects,
+            is_dead: play_layer.is_dead(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player_frame(x: f32, is_dead: bool) -> HitboxFrame {
+        HitboxFrame {
+            players: vec![PlayerHitbox {
+                rect: Rect::new(x, 0.0, 30.0, 30.0),
+                rotation: 0.0,
+            }],
+            objects: Vec::new(),
+            is_dead,
+        }
+    }
+
+    #[test]
+    fn object_kinds() {
+        assert_eq!(
+            HitboxKind::for_object(GameObjectType::Solid),
+            Some(HitboxKind::Solid)
+        );
+        assert_eq!(
+            HitboxKind::for_object(GameObjectType::Hazard),
+            Some(HitboxKind::Hazard)
+        );
+        assert_eq!(
+            HitboxKind::for_object(GameObjectType::YellowJumpPad),
+            Some(HitboxKind::Interactable)
+        );
+        assert_eq!(HitboxKind::for_object(GameObjectType::Decoration), None);
+    }
+
+    #[test]
+    fn shapes() {
+        let mut frame = player_frame(0.0, false);
+        frame.objects = vec![
+            ObjectHitbox {
+                kind: HitboxKind::Solid,
+                rect: Rect::new(30.0, 0.0, 30.0, 30.0),
+                rotation: 0.0,
+                radius: 0.0,
+            },
+            ObjectHitbox {
+                kind: HitboxKind::Hazard,
+                rect: Rect::new(60.0, 0.0, 30.0, 30.0),
+                rotation: 0.0,
+                radius: 12.0,
+            },
+        ];
+        let overlay = HitboxOverlay::default();
+        let shapes = overlay.shapes(&frame);
+        let kinds = shapes.iter().map(|s| s.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                HitboxKind::Solid,
+                HitboxKind::Hazard,
+                HitboxKind::PlayerOuter,
+                HitboxKind::PlayerInner
+            ]
+        );
+        assert_eq!(
+            shapes[1].geometry,
+            Geometry::Circle {
+                center: (75.0, 15.0),
+                radius: 12.0
+            }
+        );
+        assert_eq!(
+            shapes[3].geometry,
+            Geometry::Quad(Rect::new(10.5, 10.5, 9.0, 9.0).corners())
+        );
+        assert_eq!(shapes[0].fill.a, 0.25);
+
+        let overlay = HitboxOverlay::new(HitboxSettings {
+            show_solids: false,
+            show_inner: false,
+            ..Default::default()
+        });
+        let kinds = overlay
+            .shapes(&frame)
+            .iter()
+            .map(|s| s.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(kinds, [HitboxKind::Hazard, HitboxKind::PlayerOuter]);
+    }
+
+    #[test]
+    fn trail_on_death() {
+        let mut overlay = HitboxOverlay::new(HitboxSettings {
+            show_inner: false,
+            trail_length: 3,
+            ..Default::default()
+        });
+        for x in 0..5 {
+            let frame = player_frame(x as f32, false);
+            overlay.record(&frame);
+            // the trail only shows after dying
+            assert_eq!(overlay.shapes(&frame).len(), 1);
+        }
+        let dead = player_frame(5.0, true);
+        overlay.record(&dead);
+        assert_eq!(overlay.trail_len(), 3);
+
+        let shapes = overlay.shapes(&dead);
+        let trail = shapes
+            .iter()
+            .filter(|s| s.kind == HitboxKind::Trail)
+            .map(|s| match s.geometry {
+                Geometry::Quad(corners) => corners[0].0,
+                Geometry::Circle { .. } => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(trail, [2.0, 3.0, 4.0]);
+
+        overlay.clear_trail();
+        assert_eq!(overlay.shapes(&dead).len(), 1);
+    }
+}
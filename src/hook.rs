@@ -0,0 +1,440 @@
+//! Inline function hooks for 32-bit x86.
+//!
+//! A hook overwrites the first instructions of a function with a `jmp` to the
+//! detour. The overwritten instructions are copied to a trampoline (followed by
+//! a `jmp` back into the function), which the detour calls to run the original.
+//!
+//! ```text
+//!  target:  jmp detour ──────►  detour: ... call trampoline ... ret
+//!           <rest>  ◄──┐                        │
+//!                      │    trampoline: <stolen instructions>
+//!                      └─────────────── jmp target + stolen
+//! ```
+//!
+//! Only the instructions usually found in MSVC prologues are decoded, hooking a
+//! function that starts with anything else fails with
+//! [`HookError::UnsupportedInstruction`] instead of corrupting it.
+
+use std::fmt;
+
+/// Size of the `jmp rel32` written over the target.
+pub const JMP_LEN: usize = 5;
+
+/// Errors that can happen when creating a hook.
+#[derive(Debug)]
+pub enum HookError {
+    /// The target address is null, e.g. an export that wasn't found.
+    NullTarget,
+    /// The instruction at this offset of the target can't be decoded or moved.
+    UnsupportedInstruction { offset: usize, opcode: u8 },
+    /// The trampoline couldn't be allocated or the target couldn't be patched.
+    #[cfg(game_bindings)]
+    Memory(windows::core::Error),
+}
+
+impl fmt::Display for HookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NullTarget => write!(f, "the hook target is null"),
+            Self::UnsupportedInstruction { offset, opcode } => write!(
+                f,
+                "unsupported instruction {opcode:#04x} at offset {offset:#x}"
+            ),
+            #[cfg(game_bindings)]
+            Self::Memory(e) => write!(f, "can't write the hook: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for HookError {}
+
+#[cfg(game_bindings)]
+impl From<windows::core::Error> for HookError {
+    fn from(e: windows::core::Error) -> Self {
+        Self::Memory(e)
+    }
+}
+
+/// Length of the ModRM byte and everything after it except immediates.
+fn modrm_len(code: &[u8]) -> Option<usize> {
+    let modrm = *code.first()?;
+    let (mode, rm) = (modrm >> 6, modrm & 7);
+    if mode == 3 {
+        return Some(1);
+    }
+    let mut len = 1;
+    if rm == 4 {
+        let sib = *code.get(1)?;
+        len += 1;
+        if mode == 0 && sib & 7 == 5 {
+            len += 4;
+        }
+    } else if mode == 0 && rm == 5 {
+        len += 4;
+    }
+    len += match mode {
+        1 => 1,
+        2 => 4,
+        _ => 0,
+    };
+    Some(len)
+}
+
+/// Returns the length of the instruction at the start of `code`, `None` if it
+/// isn't supported.
+pub fn instruction_len(code: &[u8]) -> Option<usize> {
+    let mut prefixes = 0;
+    let mut operand16 = false;
+    while let Some(&b) = code.get(prefixes) {
+        match b {
+            0x66 => operand16 = true,
+            0x26 | 0x2E | 0x36 | 0x3E | 0x64 | 0x65 | 0xF2 | 0xF3 => {}
+            _ => break,
+        }
+        prefixes += 1;
+    }
+    let code = &code[prefixes..];
+    let imm_z = if operand16 { 2 } else { 4 };
+    let opcode = *code.first()?;
+    let rest = &code[1..];
+
+    let len = match opcode {
+        0x0F => {
+            let opcode2 = *rest.first()?;
+            let rest = &rest[1..];
+            match opcode2 {
+                0x80..=0x8F => 2 + 4,
+                0x10
+                | 0x11
+                | 0x14
+                | 0x28
+                | 0x29
+                | 0x2A
+                | 0x2C
+                | 0x2D
+                | 0x2E
+                | 0x2F
+                | 0x40..=0x4F
+                | 0x51
+                | 0x54
+                | 0x57..=0x5F
+                | 0x6E
+                | 0x7E
+                | 0x90..=0x9F
+                | 0xAF
+                | 0xB6
+                | 0xB7
+                | 0xBE
+                | 0xBF
+                | 0xD6 => 2 + modrm_len(rest)?,
+                _ => return None,
+            }
+        }
+        // push/pop/inc/dec reg, nop, pushad/popad, cbw/cdq, pushfd/popfd, leave, ret, int3
+        0x40..=0x61 | 0x90..=0x99 | 0x9C | 0x9D | 0xC3 | 0xC9 | 0xCC => 1,
+        // alu al, imm8 / push imm8 / mov r8, imm8 / test al, imm8 / jcc rel8 / jmp rel8 / int
+        0x04
+        | 0x0C
+        | 0x14
+        | 0x1C
+        | 0x24
+        | 0x2C
+        | 0x34
+        | 0x3C
+        | 0x6A
+        | 0x70..=0x7F
+        | 0xA8
+        | 0xB0..=0xB7
+        | 0xCD
+        | 0xEB => 2,
+        // alu eax, imm / push imm / test eax, imm / mov r32, imm
+        0x05 | 0x0D | 0x15 | 0x1D | 0x25 | 0x2D | 0x35 | 0x3D | 0x68 | 0xA9 | 0xB8..=0xBF => {
+            1 + imm_z
+        }
+        // mov eax/al, [moffs] and back
+        0xA0..=0xA3 => 1 + 4,
+        // call/jmp rel32
+        0xE8 | 0xE9 => 1 + 4,
+        // ret imm16
+        0xC2 => 3,
+        // alu r/m, reg and back / test / xchg / mov / lea / pop r/m / shifts / x87 / inc, dec, call, jmp, push r/m
+        0x00..=0x03
+        | 0x08..=0x0B
+        | 0x10..=0x13
+        | 0x18..=0x1B
+        | 0x20..=0x23
+        | 0x28..=0x2B
+        | 0x30..=0x33
+        | 0x38..=0x3B
+        | 0x84..=0x8B
+        | 0x8D
+        | 0x8F
+        | 0xD0..=0xD3
+        | 0xD8..=0xDF
+        | 0xFE
+        | 0xFF => 1 + modrm_len(rest)?,
+        // alu r/m, imm8 / imul imm8 / shifts imm8 / mov r/m8, imm8
+        0x80 | 0x82 | 0x83 | 0x6B | 0xC0 | 0xC1 | 0xC6 => 1 + modrm_len(rest)? + 1,
+        // alu r/m, imm / imul imm / mov r/m, imm
+        0x81 | 0x69 | 0xC7 => 1 + modrm_len(rest)? + imm_z,
+        // test r/m, imm (the other forms of the group don't have an immediate)
+        0xF6 | 0xF7 => {
+            let imm = match (rest.first()? >> 3) & 7 {
+                0 | 1 if opcode == 0xF6 => 1,
+                0 | 1 => imm_z,
+                _ => 0,
+            };
+            1 + modrm_len(rest)? + imm
+        }
+        _ => return None,
+    };
+    let len = prefixes + len;
+    (len <= prefixes + code.len()).then_some(len)
+}
+
+/// Returns the length of the whole instructions that cover at least `min` bytes.
+pub fn stolen_len(code: &[u8], min: usize) -> Result<usize, HookError> {
+    let mut len = 0;
+    while len < min {
+        len += instruction_len(&code[len..]).ok_or(HookError::UnsupportedInstruction {
+            offset: len,
+            opcode: code.get(len).copied().unwrap_or_default(),
+        })?;
+    }
+    Ok(len)
+}
+
+/// Copies the instructions in `code`, which is at address `from`, so they can
+/// run at address `to`, fixing relative calls and jumps.
+///
+/// Short jumps can't reach back into the function from the trampoline, so
+/// they fail with [`HookError::UnsupportedInstruction`].
+pub fn relocate(code: &[u8], from: usize, to: usize) -> Result<Vec<u8>, HookError> {
+    let mut out = code.to_vec();
+    let mut offset = 0;
+    while offset < code.len() {
+        let unsupported = HookError::UnsupportedInstruction {
+            offset,
+            opcode: code[offset],
+        };
+        let len = instruction_len(&code[offset..]).ok_or(unsupported)?;
+        let rel32 = match code[offset..] {
+            [0xE8 | 0xE9, ..] => Some(offset + 1),
+            [0x0F, 0x80..=0x8F, ..] => Some(offset + 2),
+            [0x70..=0x7F | 0xEB, ..] => {
+                return Err(HookError::UnsupportedInstruction {
+                    offset,
+                    opcode: code[offset],
+                })
+            }
+            _ => None,
+        };
+        if let Some(at) = rel32 {
+            let rel = i32::from_le_bytes(code[at..at + 4].try_into().unwrap());
+            let target = (from + offset + len).wrapping_add(rel as isize as usize);
+            let rel = target.wrapping_sub(to + offset + len) as i32;
+            out[at..at + 4].copy_from_slice(&rel.to_le_bytes());
+        }
+        offset += len;
+    }
+    Ok(out)
+}
+
+/// Encodes a `jmp rel32` at `from` to `to`.
+pub fn jmp(from: usize, to: usize) -> [u8; JMP_LEN] {
+    let rel = to.wrapping_sub(from + JMP_LEN) as i32;
+    let mut code = [0xE9, 0, 0, 0, 0];
+    code[1..].copy_from_slice(&rel.to_le_bytes());
+    code
+}
+
+/// A hook on a function of the game.
+///
+/// Creating it doesn't change anything, call [`Hook::enable`]. Dropping it
+/// disables it, the trampoline is leaked since another thread could still be
+/// running it.
+///
+/// ```no_run
+/// use geometrydash::{get_base, hook::Hook, Ptr};
+/// use std::sync::OnceLock;
+///
+/// static UPDATE: OnceLock<Hook> = OnceLock::new();
+///
+/// extern "fastcall" fn update(play_layer: Ptr, _edx: Ptr, dt: f32) {
+///     let original: extern "fastcall" fn(Ptr, Ptr, f32) =
+///         unsafe { std::mem::transmute(UPDATE.get().unwrap().trampoline()) };
+///     original(play_layer, 0, dt * 0.5)
+/// }
+///
+/// let hook = Hook::new(get_base() + 0x2029C0, update as usize).unwrap();
+/// hook.enable().unwrap();
+/// UPDATE.set(hook).ok();
+/// ```
+#[cfg(game_bindings)]
+#[derive(Debug)]
+pub struct Hook {
+    target: crate::Ptr,
+    detour: crate::Ptr,
+    trampoline: crate::Ptr,
+    original: Vec<u8>,
+    enabled: std::sync::atomic::AtomicBool,
+}
+
+#[cfg(game_bindings)]
+impl Hook {
+    /// Builds the trampoline of a hook from `target` to `detour`.
+    pub fn new(target: crate::Ptr, detour: crate::Ptr) -> Result<Self, HookError> {
+        use windows::Win32::System::Memory::{
+            VirtualAlloc, MEM_COMMIT, MEM_RESERVE, PAGE_EXECUTE_READWRITE,
+        };
+
+        if target == 0 {
+            return Err(HookError::NullTarget);
+        }
+
+        // long enough for any instruction that starts in the first 5 bytes
+        let code = unsafe { std::slice::from_raw_parts(target as *const u8, JMP_LEN + 15) };
+        let len = stolen_len(code, JMP_LEN)?;
+        let original = code[..len].to_vec();
+
+        let trampoline = unsafe {
+            VirtualAlloc(
+                None,
+                len + JMP_LEN,
+                MEM_COMMIT | MEM_RESERVE,
+                PAGE_EXECUTE_READWRITE,
+            )
+        } as crate::Ptr;
+        if trampoline == 0 {
+            return Err(windows::core::Error::from_win32().into());
+        }
+        let mut code = relocate(&original, target, trampoline)?;
+        code.extend_from_slice(&jmp(trampoline + len, target + len));
+        unsafe { std::ptr::copy_nonoverlapping(code.as_ptr(), trampoline as *mut u8, code.len()) };
+
+        Ok(Self {
+            target,
+            detour,
+            trampoline,
+            original,
+            enabled: std::sync::atomic::AtomicBool::new(false),
+        })
+    }
+
+    /// Redirects the target to the detour.
+    pub fn enable(&self) -> Result<(), HookError> {
+        let mut code = self.original.clone();
+        code[..JMP_LEN].copy_from_slice(&jmp(self.target, self.detour));
+        // pad with int3 so nothing jumps into the middle of a stolen instruction unnoticed
+        code[JMP_LEN..].fill(0xCC);
+        crate::patch_mem(self.target, &code)?;
+        self.enabled
+            .store(true, std::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Restores the original instructions of the target.
+    pub fn disable(&self) -> Result<(), HookError> {
+        crate::patch_mem(self.target, &self.original)?;
+        self.enabled
+            .store(false, std::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Address of the function that runs the original target.
+    #[inline]
+    pub fn trampoline(&self) -> crate::Ptr {
+        self.trampoline
+    }
+
+    #[inline]
+    pub fn target(&self) -> crate::Ptr {
+        self.target
+    }
+}
+
+#[cfg(game_bindings)]
+impl Drop for Hook {
+    fn drop(&mut self) {
+        if self.is_enabled() {
+            let _ = self.disable();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prologue_lengths() {
+        let cases: &[(&[u8], usize)] = &[
+            (&[0x55], 1),                                     // push ebp
+            (&[0x8B, 0xEC], 2),                               // mov ebp, esp
+            (&[0x83, 0xEC, 0x10], 3),                         // sub esp, 0x10
+            (&[0x81, 0xEC, 0x00, 0x01, 0x00, 0x00], 6),       // sub esp, 0x100
+            (&[0x6A, 0xFF], 2),                               // push -1
+            (&[0x68, 0x78, 0x56, 0x34, 0x12], 5),             // push imm32
+            (&[0x64, 0xA1, 0x00, 0x00, 0x00, 0x00], 6),       // mov eax, fs:[0]
+            (&[0x8B, 0x44, 0x24, 0x04], 4),                   // mov eax, [esp+4]
+            (&[0x8B, 0x0D, 0x78, 0x56, 0x34, 0x12], 6),       // mov ecx, [imm32]
+            (&[0x8B, 0x84, 0x24, 0x00, 0x01, 0x00, 0x00], 7), // mov eax, [esp+0x100]
+            (&[0xF3, 0x0F, 0x10, 0x45, 0x08], 5),             // movss xmm0, [ebp+8]
+            (&[0x66, 0xC7, 0x00, 0x34, 0x12], 5),             // mov word [eax], 0x1234
+            (&[0xF6, 0x41, 0x04, 0x01], 4),                   // test byte [ecx+4], 1
+            (&[0xF7, 0xD8], 2),                               // neg eax
+            (&[0xE8, 0x00, 0x00, 0x00, 0x00], 5),             // call rel32
+        ];
+        for (code, len) in cases {
+            assert_eq!(instruction_len(code), Some(*len), "{code:02x?}");
+        }
+        assert_eq!(instruction_len(&[0x0F, 0x05]), None); // syscall
+        assert_eq!(instruction_len(&[0x81, 0xEC, 0x00]), None); // truncated
+    }
+
+    #[test]
+    fn stolen_instructions() {
+        // push ebp; mov ebp, esp; push -1; push imm32
+        let code = [0x55, 0x8B, 0xEC, 0x6A, 0xFF, 0x68, 0, 0, 0, 0];
+        assert_eq!(stolen_len(&code, JMP_LEN).unwrap(), 5);
+        let code = [0x55, 0x8B, 0xEC, 0x83, 0xEC, 0x10];
+        assert_eq!(stolen_len(&code, JMP_LEN).unwrap(), 6);
+        assert!(matches!(
+            stolen_len(&[0x55, 0x0F, 0x05], JMP_LEN),
+            Err(HookError::UnsupportedInstruction {
+                offset: 1,
+                opcode: 0x0F
+            })
+        ));
+    }
+
+    #[test]
+    fn relocation() {
+        // push ebp; call 0x2000 (from 0x1000)
+        let rel = (0x2000i32 - 0x1006).to_le_bytes();
+        let code = [0x55, 0xE8, rel[0], rel[1], rel[2], rel[3]];
+        let moved = relocate(&code, 0x1000, 0x5000).unwrap();
+        let rel = i32::from_le_bytes(moved[2..6].try_into().unwrap());
+        assert_eq!(0x5006 + rel as isize, 0x2000);
+        assert_eq!(moved[..2], code[..2]);
+
+        // jmp short
+        assert!(relocate(&[0xEB, 0x10], 0x1000, 0x5000).is_err());
+
+        let code = jmp(0x5000, 0x1000);
+        assert_eq!(code[0], 0xE9);
+        let rel = i32::from_le_bytes(code[1..].try_into().unwrap());
+        assert_eq!(0x5005 + rel as isize, 0x1000);
+    }
+
+    #[test]
+    #[cfg(game_bindings)]
+    fn null_targets() {
+        assert!(matches!(Hook::new(0, 0x1000), Err(HookError::NullTarget)));
+    }
+}
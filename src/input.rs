@@ -0,0 +1,637 @@
+//! Hotkeys.
+//!
+//! [`Hotkeys`] maps key combos (`Ctrl+R`, `F3`...) to callbacks, each of which
+//! can be limited to some scenes. It only tracks key presses and doesn't touch
+//! the game; `install` hooks `CCKeyboardDispatcher::dispatchKeyboardMSG` to
+//! feed it the game's key presses.
+//!
+//! Scenes are named after the class of the main layer of the running scene,
+//! e.g. `PlayLayer` or `MenuLayer`.
+
+use std::{fmt, str::FromStr, sync::Arc};
+
+/// A Windows virtual-key code, which cocos2d uses for its key codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct KeyCode(pub u32);
+
+impl KeyCode {
+    pub const BACKSPACE: Self = Self(0x08);
+    pub const TAB: Self = Self(0x09);
+    pub const ENTER: Self = Self(0x0D);
+    pub const SHIFT: Self = Self(0x10);
+    pub const CONTROL: Self = Self(0x11);
+    pub const ALT: Self = Self(0x12);
+    pub const ESCAPE: Self = Self(0x1B);
+    pub const SPACE: Self = Self(0x20);
+    pub const LEFT: Self = Self(0x25);
+    pub const UP: Self = Self(0x26);
+    pub const RIGHT: Self = Self(0x27);
+    pub const DOWN: Self = Self(0x28);
+    pub const DELETE: Self = Self(0x2E);
+    pub const F1: Self = Self(0x70);
+
+    const NAMES: [(&'static str, KeyCode); 13] = [
+        ("Backspace", Self::BACKSPACE),
+        ("Tab", Self::TAB),
+        ("Enter", Self::ENTER),
+        ("Shift", Self::SHIFT),
+        ("Ctrl", Self::CONTROL),
+        ("Alt", Self::ALT),
+        ("Escape", Self::ESCAPE),
+        ("Space", Self::SPACE),
+        ("Left", Self::LEFT),
+        ("Up", Self::UP),
+        ("Right", Self::RIGHT),
+        ("Down", Self::DOWN),
+        ("Delete", Self::DELETE),
+    ];
+
+    /// Returns the key for a letter or digit.
+    pub fn from_char(c: char) -> Option<Self> {
+        let c = c.to_ascii_uppercase();
+        (c.is_ascii_uppercase() || c.is_ascii_digit()).then_some(Self(c as u32))
+    }
+
+    /// Returns the key for `F1` to `F24`.
+    pub fn function(n: u32) -> Option<Self> {
+        (1..=24).contains(&n).then_some(Self(Self::F1.0 + n - 1))
+    }
+
+    /// Parses a key name: a letter, a digit, `F1`-`F24` or one of the named keys
+    /// (`Space`, `Escape`...). Case-insensitive.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let mut chars = name.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Self::from_char(c);
+        }
+        if let Some(n) = name
+            .strip_prefix(['F', 'f'])
+            .and_then(|n| n.parse::<u32>().ok())
+        {
+            return Self::function(n);
+        }
+        Self::NAMES
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, key)| *key)
+    }
+}
+
+impl fmt::Display for KeyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((name, _)) = Self::NAMES.iter().find(|(_, key)| key == self) {
+            f.write_str(name)
+        } else if (Self::F1.0..Self::F1.0 + 24).contains(&self.0) {
+            write!(f, "F{}", self.0 - Self::F1.0 + 1)
+        } else if let Some(c) = char::from_u32(self.0).filter(|c| c.is_ascii_alphanumeric()) {
+            write!(f, "{c}")
+        } else {
+            write!(f, "{:#04x}", self.0)
+        }
+    }
+}
+
+/// Modifier keys held with a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Modifiers {
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+impl Modifiers {
+    pub const NONE: Self = Self {
+        ctrl: false,
+        shift: false,
+        alt: false,
+    };
+}
+
+/// A key with the modifiers that have to be held with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyCombo {
+    pub key: KeyCode,
+    pub modifiers: Modifiers,
+}
+
+impl KeyCombo {
+    pub const fn new(key: KeyCode, modifiers: Modifiers) -> Self {
+        Self { key, modifiers }
+    }
+}
+
+impl From<KeyCode> for KeyCombo {
+    fn from(key: KeyCode) -> Self {
+        Self::new(key, Modifiers::NONE)
+    }
+}
+
+/// Error returned when a key combo can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseComboError(String);
+
+impl fmt::Display for ParseComboError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid key combo {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseComboError {}
+
+/// Parses combos like `R`, `Ctrl+Shift+R` or `Alt + F4`.
+impl FromStr for KeyCombo {
+    type Err = ParseComboError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseComboError(s.to_string());
+        let mut parts = s.split('+').map(str::trim).collect::<Vec<_>>();
+        let key = parts.pop().and_then(KeyCode::from_name).ok_or_else(err)?;
+        let mut modifiers = Modifiers::NONE;
+        for part in parts {
+            let modifier = match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => &mut modifiers.ctrl,
+                "shift" => &mut modifiers.shift,
+                "alt" => &mut modifiers.alt,
+                _ => return Err(err()),
+            };
+            *modifier = true;
+        }
+        Ok(Self::new(key, modifiers))
+    }
+}
+
+impl fmt::Display for KeyCombo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.ctrl {
+            f.write_str("Ctrl+")?;
+        }
+        if self.modifiers.shift {
+            f.write_str("Shift+")?;
+        }
+        if self.modifiers.alt {
+            f.write_str("Alt+")?;
+        }
+        write!(f, "{}", self.key)
+    }
+}
+
+/// Scenes a hotkey works in.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SceneFilter {
+    #[default]
+    Any,
+    Only(Vec<String>),
+    Except(Vec<String>),
+}
+
+impl SceneFilter {
+    /// Returns whether the filter lets a hotkey fire in `scene` (`None` if unknown).
+    pub fn allows(&self, scene: Option<&str>) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Only(scenes) => scene.is_some_and(|s| scenes.iter().any(|n| n == s)),
+            Self::Except(scenes) => !scene.is_some_and(|s| scenes.iter().any(|n| n == s)),
+        }
+    }
+}
+
+/// Identifies a hotkey of a [`Hotkeys`] registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HotkeyId(u32);
+
+pub type HotkeyCallback = Arc<dyn Fn() + Send + Sync>;
+
+struct Hotkey {
+    id: HotkeyId,
+    combo: KeyCombo,
+    scenes: SceneFilter,
+    enabled: bool,
+    callback: HotkeyCallback,
+}
+
+/// What a key event did, returned by [`Hotkeys::key_event`].
+#[derive(Default)]
+pub struct KeyEventResult {
+    /// Callbacks of the hotkeys that fired.
+    pub callbacks: Vec<HotkeyCallback>,
+    /// Whether the event shouldn't reach the game: a press that fired a
+    /// hotkey, or the repeats and release of such a press.
+    pub consumed: bool,
+}
+
+/// A registry of hotkeys.
+#[derive(Default)]
+pub struct Hotkeys {
+    hotkeys: Vec<Hotkey>,
+    next_id: u32,
+    /// Held keys, and whether their press fired a hotkey.
+    pressed: Vec<(KeyCode, bool)>,
+}
+
+impl fmt::Debug for Hotkeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.hotkeys.iter().map(|h| (h.id, h.combo)))
+            .finish()
+    }
+}
+
+impl Hotkeys {
+    pub const fn new() -> Self {
+        Self {
+            hotkeys: Vec::new(),
+            next_id: 0,
+            pressed: Vec::new(),
+        }
+    }
+
+    /// Registers a callback for a key combo, in every scene.
+    pub fn register<C, F>(&mut self, combo: C, callback: F) -> HotkeyId
+    where
+        C: Into<KeyCombo>,
+        F: Fn() + Send + Sync + 'static,
+    {
+        let id = HotkeyId(self.next_id);
+        self.next_id += 1;
+        self.hotkeys.push(Hotkey {
+            id,
+            combo: combo.into(),
+            scenes: SceneFilter::Any,
+            enabled: true,
+            callback: Arc::new(callback),
+        });
+        id
+    }
+
+    /// Removes a hotkey, returns whether it existed.
+    pub fn unregister(&mut self, id: HotkeyId) -> bool {
+        let len = self.hotkeys.len();
+        self.hotkeys.retain(|h| h.id != id);
+        self.hotkeys.len() != len
+    }
+
+    /// Removes every hotkey.
+    pub fn clear(&mut self) {
+        self.hotkeys.clear();
+    }
+
+    pub fn set_enabled(&mut self, id: HotkeyId, enabled: bool) {
+        if let Some(hotkey) = self.hotkeys.iter_mut().find(|h| h.id == id) {
+            hotkey.enabled = enabled;
+        }
+    }
+
+    /// Limits the scenes a hotkey works in.
+    pub fn set_scenes(&mut self, id: HotkeyId, scenes: SceneFilter) {
+        if let Some(hotkey) = self.hotkeys.iter_mut().find(|h| h.id == id) {
+            hotkey.scenes = scenes;
+        }
+    }
+
+    pub fn combo(&self, id: HotkeyId) -> Option<KeyCombo> {
+        self.hotkeys.iter().find(|h| h.id == id).map(|h| h.combo)
+    }
+
+    /// Forgets the held keys, e.g. when the window loses focus and their
+    /// releases go to another window. Their next press fires hotkeys again.
+    pub fn release_all(&mut self) {
+        self.pressed.clear();
+    }
+
+    /// Handles a key press or release and returns the callbacks to run.
+    ///
+    /// Callbacks are returned instead of being called so they can register
+    /// hotkeys themselves. Holding a key only fires its hotkeys once.
+    pub fn key_event(
+        &mut self,
+        key: KeyCode,
+        down: bool,
+        modifiers: Modifiers,
+        scene: Option<&str>,
+    ) -> KeyEventResult {
+        let held = self.pressed.iter().position(|(k, _)| *k == key);
+        if !down {
+            let consumed = held.is_some_and(|i| self.pressed.remove(i).1);
+            return KeyEventResult {
+                callbacks: Vec::new(),
+                consumed,
+            };
+        }
+        if let Some(i) = held {
+            return KeyEventResult {
+                callbacks: Vec::new(),
+                consumed: self.pressed[i].1,
+            };
+        }
+        let combo = KeyCombo::new(key, modifiers);
+        let callbacks: Vec<_> = self
+            .hotkeys
+            .iter()
+            .filter(|h| h.enabled && h.combo == combo && h.scenes.allows(scene))
+            .map(|h| h.callback.clone())
+            .collect();
+        let consumed = !callbacks.is_empty();
+        self.pressed.push((key, consumed));
+        KeyEventResult {
+            callbacks,
+            consumed,
+        }
+    }
+}
+
+#[cfg(game_bindings)]
+mod hook {
+    use super::*;
+    use crate::{
+        cocos2d::{get_hmod, CCDirector},
+        hook::{Hook, HookError},
+        AddressUtils, Ptr,
+    };
+    use std::sync::{
+        atomic::{AtomicBool, AtomicIsize, Ordering},
+        Mutex, MutexGuard, Once, OnceLock,
+    };
+    use windows::Win32::{
+        Foundation::{HINSTANCE, LPARAM, LRESULT, WPARAM},
+        System::Threading::GetCurrentThreadId,
+        UI::WindowsAndMessaging::{
+            CallNextHookEx, SetWindowsHookExW, UnhookWindowsHookEx, CWPSTRUCT, HHOOK,
+            WH_CALLWNDPROC, WM_KILLFOCUS,
+        },
+    };
+
+    static HOTKEYS: Mutex<Hotkeys> = Mutex::new(Hotkeys::new());
+    static HOOK: OnceLock<Hook> = OnceLock::new();
+    static CLEANUP: Once = Once::new();
+    /// Window hook of the game's thread that watches for focus changes.
+    static FOCUS_HOOK: AtomicIsize = AtomicIsize::new(0);
+    static FOCUS_LOST: AtomicBool = AtomicBool::new(false);
+
+    /// Returns the registry [`install`] feeds key presses to.
+    pub fn hotkeys() -> MutexGuard<'static, Hotkeys> {
+        HOTKEYS.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Hooks the keyboard dispatcher so the game's key presses fire [`hotkeys`].
+    ///
+    /// Key presses that fire a hotkey aren't passed to the game, and keys held
+    /// when the window loses focus count as released. The hook is removed
+    /// when the mod is unloaded.
+    ///
+    /// ```no_run
+    /// use geometrydash::{input, AddressUtils, GameManager};
+    ///
+    /// input::hotkeys().register("Ctrl+R".parse::<input::KeyCombo>().unwrap(), || {
+    ///     if let Some(play_layer) = GameManager::shared().play_layer().to_option() {
+    ///         play_layer.reset_level();
+    ///     }
+    /// });
+    /// input::install().unwrap();
+    /// ```
+    pub fn install() -> Result<(), HookError> {
+        let hook = match HOOK.get() {
+            Some(hook) => hook,
+            None => {
+                let target = unsafe {
+                    windows::Win32::System::LibraryLoader::GetProcAddress(
+                        get_hmod(),
+                        windows::core::s!(
+                            "?dispatchKeyboardMSG@CCKeyboardDispatcher@cocos2d@@QAE_NW4enumKeyCodes@2@_N@Z"
+                        ),
+                    )
+                }
+                .map_or(0, |f| f as Ptr);
+                let hook = Hook::new(target, dispatch_keyboard_msg as DispatchKeyboardMsg as Ptr)?;
+                HOOK.get_or_init(|| hook)
+            }
+        };
+        CLEANUP.call_once(|| {
+            crate::modding::on_cleanup(|| {
+                let _ = uninstall();
+            })
+        });
+        hook.enable()
+    }
+
+    /// Removes the keyboard hook. The registered hotkeys are kept.
+    pub fn uninstall() -> Result<(), HookError> {
+        let focus_hook = FOCUS_HOOK.swap(0, Ordering::Relaxed);
+        if focus_hook != 0 {
+            let _ = unsafe { UnhookWindowsHookEx(HHOOK(focus_hook)) };
+        }
+        match HOOK.get() {
+            Some(hook) => hook.disable(),
+            None => Ok(()),
+        }
+    }
+
+    /// Starts watching for focus changes, from the thread of the game's window.
+    fn watch_focus() {
+        if FOCUS_HOOK.load(Ordering::Relaxed) != 0 {
+            return;
+        }
+        let hook = unsafe {
+            SetWindowsHookExW(
+                WH_CALLWNDPROC,
+                Some(call_wnd_proc),
+                HINSTANCE::default(),
+                GetCurrentThreadId(),
+            )
+        };
+        match hook {
+            Ok(hook) => FOCUS_HOOK.store(hook.0, Ordering::Relaxed),
+            Err(e) => log::warn!("can't watch the window focus: {e}"),
+        }
+    }
+
+    unsafe extern "system" fn call_wnd_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+        // the key releases go to the new window, forget the held keys before
+        // the next key event (the hotkeys may be locked right now)
+        if code >= 0 && (*(lparam.0 as *const CWPSTRUCT)).message == WM_KILLFOCUS {
+            FOCUS_LOST.store(true, Ordering::Relaxed);
+        }
+        CallNextHookEx(
+            HHOOK(FOCUS_HOOK.load(Ordering::Relaxed)),
+            code,
+            wparam,
+            lparam,
+        )
+    }
+
+    /// Class name of the main layer of the running scene.
+    pub fn current_scene_name() -> Option<String> {
+        let scene = CCDirector::shared().running_scene().to_option()?;
+        scene.child(0)?.class_name()
+    }
+
+    fn modifiers() -> Modifiers {
+        use windows::Win32::UI::Input::KeyboardAndMouse::{
+            GetKeyState, VK_CONTROL, VK_MENU, VK_SHIFT,
+        };
+
+        let held = |vk: windows::Win32::UI::Input::KeyboardAndMouse::VIRTUAL_KEY| unsafe {
+            GetKeyState(vk.0 as i32) < 0
+        };
+        Modifiers {
+            ctrl: held(VK_CONTROL),
+            shift: held(VK_SHIFT),
+            alt: held(VK_MENU),
+        }
+    }
+
+    type DispatchKeyboardMsg = extern "thiscall" fn(Ptr, u32, bool) -> bool;
+
+    extern "thiscall" fn dispatch_keyboard_msg(this: Ptr, key: u32, down: bool) -> bool {
+        let consumed = crate::guard::call("hotkeys", || {
+            watch_focus();
+            let scene = current_scene_name();
+            let result = {
+                let mut hotkeys = hotkeys();
+                if FOCUS_LOST.swap(false, Ordering::Relaxed) {
+                    hotkeys.release_all();
+                }
+                hotkeys.key_event(KeyCode(key), down, modifiers(), scene.as_deref())
+            };
+            for callback in &result.callbacks {
+                crate::guard::call("hotkey", || callback());
+            }
+            result.consumed
+        });
+        if consumed == Some(true) {
+            return true;
+        }
+        let original: DispatchKeyboardMsg =
+            unsafe { std::mem::transmute(HOOK.get().unwrap().trampoline()) };
+        original(this, key, down)
+    }
+}
+
+#[cfg(game_bindings)]
+pub use hook::{current_scene_name, hotkeys, install, uninstall};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn parse_combos() {
+        let combo = "Ctrl+Shift+R".parse::<KeyCombo>().unwrap();
+        assert_eq!(combo.key, KeyCode(b'R' as u32));
+        assert!(combo.modifiers.ctrl && combo.modifiers.shift && !combo.modifiers.alt);
+        assert_eq!(combo.to_string(), "Ctrl+Shift+R");
+
+        assert_eq!(
+            "alt + f4".parse::<KeyCombo>().unwrap(),
+            KeyCombo::new(
+                KeyCode(0x73),
+                Modifiers {
+                    alt: true,
+                    ..Modifiers::NONE
+                }
+            )
+        );
+        assert_eq!("space".parse::<KeyCombo>().unwrap(), KeyCode::SPACE.into());
+        assert!("Hyper+R".parse::<KeyCombo>().is_err());
+        assert!("Ctrl+".parse::<KeyCombo>().is_err());
+        assert!("F25".parse::<KeyCombo>().is_err());
+    }
+
+    #[test]
+    fn hotkeys_fire_once_per_press() {
+        let count = Arc::new(AtomicU32::new(0));
+        let mut hotkeys = Hotkeys::new();
+        let counter = count.clone();
+        let restart = hotkeys.register("Ctrl+R".parse::<KeyCombo>().unwrap(), move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+        hotkeys.set_scenes(restart, SceneFilter::Only(vec!["PlayLayer".into()]));
+
+        let ctrl = Modifiers {
+            ctrl: true,
+            ..Modifiers::NONE
+        };
+        let r = KeyCode::from_char('r').unwrap();
+        let fire = |hotkeys: &mut Hotkeys, down, modifiers, scene| {
+            let result = hotkeys.key_event(r, down, modifiers, scene);
+            for callback in &result.callbacks {
+                callback();
+            }
+            result.consumed
+        };
+
+        assert!(fire(&mut hotkeys, true, ctrl, Some("PlayLayer")));
+        // key repeat, and the release (even after letting go of Ctrl) are
+        // swallowed too
+        assert!(fire(&mut hotkeys, true, ctrl, Some("PlayLayer")));
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+        assert!(fire(
+            &mut hotkeys,
+            false,
+            Modifiers::NONE,
+            Some("PlayLayer")
+        ));
+
+        // wrong modifiers or scene
+        assert!(!fire(
+            &mut hotkeys,
+            true,
+            Modifiers::NONE,
+            Some("PlayLayer")
+        ));
+        assert!(!fire(&mut hotkeys, true, ctrl, Some("PlayLayer")));
+        assert!(!fire(
+            &mut hotkeys,
+            false,
+            Modifiers::NONE,
+            Some("PlayLayer")
+        ));
+        fire(&mut hotkeys, true, ctrl, Some("MenuLayer"));
+        fire(&mut hotkeys, false, ctrl, Some("MenuLayer"));
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+
+        hotkeys.set_enabled(restart, false);
+        fire(&mut hotkeys, true, ctrl, Some("PlayLayer"));
+        fire(&mut hotkeys, false, ctrl, Some("PlayLayer"));
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+
+        hotkeys.set_enabled(restart, true);
+        fire(&mut hotkeys, true, ctrl, Some("PlayLayer"));
+        assert_eq!(count.load(Ordering::Relaxed), 2);
+
+        assert!(hotkeys.unregister(restart));
+        assert!(hotkeys.combo(restart).is_none());
+    }
+
+    #[test]
+    fn release_all_after_focus_loss() {
+        let count = Arc::new(AtomicU32::new(0));
+        let mut hotkeys = Hotkeys::new();
+        let counter = count.clone();
+        hotkeys.register(KeyCode::F1, move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+        let press = |hotkeys: &mut Hotkeys| {
+            let result = hotkeys.key_event(KeyCode::F1, true, Modifiers::NONE, None);
+            result.callbacks.iter().for_each(|callback| callback());
+            result.consumed
+        };
+
+        assert!(press(&mut hotkeys));
+        // the release went to another window, the next press would be a repeat
+        assert!(press(&mut hotkeys));
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+        hotkeys.release_all();
+        assert!(press(&mut hotkeys));
+        assert_eq!(count.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn scene_filters() {
+        assert!(SceneFilter::Any.allows(None));
+        let except = SceneFilter::Except(vec!["LevelEditorLayer".into()]);
+        assert!(except.allows(Some("PlayLayer")));
+        assert!(!except.allows(Some("LevelEditorLayer")));
+        assert!(!SceneFilter::Only(vec!["PlayLayer".into()]).allows(None));
+    }
+}
@@ -0,0 +1,298 @@
+//! Scene graph inspector.
+//!
+//! [`dump_scene`] takes a snapshot of the running scene as a tree of
+//! [`NodeDump`]s, which can be saved as JSON and compared with [`diff`].
+
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+/// Snapshot of a node and its children.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeDump {
+    /// Class name from the RTTI, e.g. `PlayLayer` or `cocos2d::CCSprite`.
+    pub class_name: String,
+    /// Address of the node when it was dumped.
+    pub address: usize,
+    pub tag: i32,
+    pub position: (f32, f32),
+    pub content_size: (f32, f32),
+    pub visible: bool,
+    pub children: Vec<NodeDump>,
+}
+
+impl NodeDump {
+    /// Creates a dump without children.
+    pub fn new<S: Into<String>>(class_name: S, address: usize) -> Self {
+        Self {
+            class_name: class_name.into(),
+            address,
+            tag: -1,
+            position: (0.0, 0.0),
+            content_size: (0.0, 0.0),
+            visible: true,
+            children: Vec::new(),
+        }
+    }
+
+    /// Serializes the tree to pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        // serializing plain structs into a string can't fail
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Reads a tree back from JSON.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Number of nodes in the tree, including this one.
+    pub fn node_count(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .map(NodeDump::node_count)
+            .sum::<usize>()
+    }
+
+    /// Iterates over this node and every node below it, depth-first.
+    pub fn iter(&self) -> impl Iterator<Item = &NodeDump> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+}
+
+/// A difference between two dumps, found by [`diff`].
+///
+/// `path` is the list of class names (with the index among siblings) from
+/// the root to the node, e.g. `cocos2d::CCScene/PlayLayer[0]/cocos2d::CCLayer[3]`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    /// A node that only exists in the new dump.
+    Added { path: String, address: usize },
+    /// A node that only exists in the old dump.
+    Removed { path: String, address: usize },
+    /// A property of a node changed.
+    Changed {
+        path: String,
+        field: &'static str,
+        old: String,
+        new: String,
+    },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added { path, address } => write!(f, "+ {path} ({address:#x})"),
+            Self::Removed { path, address } => write!(f, "- {path} ({address:#x})"),
+            Self::Changed {
+                path,
+                field,
+                old,
+                new,
+            } => write!(f, "~ {path}: {field} {old} -> {new}"),
+        }
+    }
+}
+
+/// Compares two dumps and returns what changed from `old` to `new`.
+///
+/// Nodes are matched by address, since a node keeps its address for as long
+/// as it lives. Children that were replaced by a new node at the same index
+/// show up as one removal and one addition.
+pub fn diff(old: &NodeDump, new: &NodeDump) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let root = segment(new, None);
+    if old.address != new.address {
+        changes.push(Change::Removed {
+            path: segment(old, None),
+            address: old.address,
+        });
+        changes.push(Change::Added {
+            path: root,
+            address: new.address,
+        });
+    } else {
+        diff_node(old, new, &root, &mut changes);
+    }
+    changes
+}
+
+fn segment(node: &NodeDump, index: Option<usize>) -> String {
+    match index {
+        Some(index) => format!("{}[{index}]", node.class_name),
+        None => node.class_name.clone(),
+    }
+}
+
+fn diff_node(old: &NodeDump, new: &NodeDump, path: &str, changes: &mut Vec<Change>) {
+    macro_rules! compare {
+        ($field:ident) => {
+            if old.$field != new.$field {
+                changes.push(Change::Changed {
+                    path: path.to_owned(),
+                    field: stringify!($field),
+                    old: format!("{:?}", old.$field),
+                    new: format!("{:?}", new.$field),
+                });
+            }
+        };
+    }
+    compare!(class_name);
+    compare!(tag);
+    compare!(position);
+    compare!(content_size);
+    compare!(visible);
+
+    // batch nodes can have thousands of children
+    let mut old_children = HashMap::with_capacity(old.children.len());
+    for child in &old.children {
+        old_children.entry(child.address).or_insert(child);
+    }
+    let new_addresses: HashSet<_> = new.children.iter().map(|c| c.address).collect();
+
+    for (i, child) in old.children.iter().enumerate() {
+        if !new_addresses.contains(&child.address) {
+            changes.push(Change::Removed {
+                path: format!("{path}/{}", segment(child, Some(i))),
+                address: child.address,
+            });
+        }
+    }
+    for (i, child) in new.children.iter().enumerate() {
+        let child_path = format!("{path}/{}", segment(child, Some(i)));
+        match old_children.get(&child.address) {
+            Some(old_child) => diff_node(old_child, child, &child_path, changes),
+            None => changes.push(Change::Added {
+                path: child_path,
+                address: child.address,
+            }),
+        }
+    }
+}
+
+/// Takes a snapshot of `node` and everything below it.
+#[cfg(game_bindings)]
+pub fn dump_node(node: crate::cocos2d::CCNode) -> NodeDump {
+    use crate::AddressUtils;
+
+    let size = node.content_size();
+    NodeDump {
+        class_name: node.class_name().unwrap_or_else(|| "<unknown>".to_owned()),
+        address: node.ptr(),
+        tag: node.tag(),
+        position: node.position().into(),
+        content_size: (size.width, size.height),
+        visible: node.is_visible(),
+        children: node.iter_children().map(dump_node).collect(),
+    }
+}
+
+/// Takes a snapshot of the running scene, if there is one.
+///
+/// ```no_run
+/// if let Some(scene) = geometrydash::inspector::dump_scene() {
+///     std::fs::write("scene.json", scene.to_json()).unwrap();
+/// }
+/// ```
+#[cfg(game_bindings)]
+pub fn dump_scene() -> Option<NodeDump> {
+    use crate::AddressUtils;
+
+    let scene = crate::cocos2d::CCDirector::shared()
+        .running_scene()
+        .to_option()?;
+    Some(dump_node(scene))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree() -> NodeDump {
+        let mut scene = NodeDump::new("cocos2d::CCScene", 0x1000);
+        let mut layer = NodeDump::new("PlayLayer", 0x2000);
+        layer.tag = 5;
+        layer.children.push(NodeDump::new("PlayerObject", 0x3000));
+        layer.children.push(NodeDump::new("PlayerObject", 0x4000));
+        scene.children.push(layer);
+        scene
+            .children
+            .push(NodeDump::new("cocos2d::CCLabelBMFont", 0x5000));
+        scene
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let scene = tree();
+        let json = scene.to_json();
+        assert!(json.contains("\"class_name\": \"PlayLayer\""));
+        assert_eq!(NodeDump::from_json(&json).unwrap(), scene);
+        assert_eq!(scene.node_count(), 5);
+    }
+
+    #[test]
+    fn iter_is_depth_first() {
+        let addresses: Vec<usize> = tree().iter().map(|n| n.address).collect();
+        assert_eq!(addresses, [0x1000, 0x2000, 0x3000, 0x4000, 0x5000]);
+    }
+
+    #[test]
+    fn diff_identical() {
+        assert!(diff(&tree(), &tree()).is_empty());
+    }
+
+    #[test]
+    fn diff_changes() {
+        let old = tree();
+        let mut new = tree();
+        new.children[0].children.remove(0);
+        new.children[0].children[0].position = (10.0, 20.0);
+        new.children[1].visible = false;
+        new.children.push(NodeDump::new("PauseLayer", 0x6000));
+
+        let changes = diff(&old, &new);
+        assert_eq!(
+            changes,
+            [
+                Change::Removed {
+                    path: "cocos2d::CCScene/PlayLayer[0]/PlayerObject[0]".into(),
+                    address: 0x3000,
+                },
+                Change::Changed {
+                    path: "cocos2d::CCScene/PlayLayer[0]/PlayerObject[0]".into(),
+                    field: "position",
+                    old: "(0.0, 0.0)".into(),
+                    new: "(10.0, 20.0)".into(),
+                },
+                Change::Changed {
+                    path: "cocos2d::CCScene/cocos2d::CCLabelBMFont[1]".into(),
+                    field: "visible",
+                    old: "true".into(),
+                    new: "false".into(),
+                },
+                Change::Added {
+                    path: "cocos2d::CCScene/PauseLayer[2]".into(),
+                    address: 0x6000,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_different_root() {
+        let old = tree();
+        let mut new = tree();
+        new.address = 0x9000;
+        let changes = diff(&old, &new);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[1].to_string(), "+ cocos2d::CCScene (0x9000)");
+    }
+}
@@ -1,4 +1,16 @@
-use crate::{impl_addr_funcs, read_mem, Ptr};
+// https://github.com/maxnut/gd.h/blob/436af2c6440a7efd1ba4720e48429fddaaf298e4/layers_scenes_transitions_nodes/LevelSettingsObject.h
+//
+// Also used for the settings of start positions.
+// IconType LevelSettingsObject.startMode = LevelSettingsObject + 0xF0
+// Speed LevelSettingsObject.startSpeed = LevelSettingsObject + 0xF4
+// bool LevelSettingsObject.startMini = LevelSettingsObject + 0xF8
+// bool LevelSettingsObject.startDual = LevelSettingsObject + 0xF9
+// bool LevelSettingsObject.twoPlayerMode = LevelSettingsObject + 0xFA
+// float LevelSettingsObject.songOffset = LevelSettingsObject + 0xFC
+// bool LevelSettingsObject.isFlipped = LevelSettingsObject + 0x114
+// bool LevelSettingsObject.isMirrored = LevelSettingsObject + 0x115
+
+use crate::{impl_addr_funcs, impl_get_set, read_mem, GameMode, Ptr};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
@@ -16,6 +28,24 @@ impl LevelSettings {
     pub fn is_2player(&self) -> bool {
         unsafe { *read_mem(self.address + 0xFA) }
     }
+
+    impl_get_set!(start_mode_raw, set_start_mode_raw, i32, 0xF0);
+    impl_get_set!(start_speed_raw, set_start_speed_raw, i32, 0xF4);
+    impl_get_set!(start_mini, set_start_mini, bool, 0xF8);
+    impl_get_set!(start_dual, set_start_dual, bool, 0xF9);
+    impl_get_set!(song_offset, set_song_offset, f32, 0xFC);
+    impl_get_set!(start_flipped, set_start_flipped, bool, 0x114);
+    impl_get_set!(start_mirrored, set_start_mirrored, bool, 0x115);
+
+    /// Gamemode the player starts in, cube if the value is unknown.
+    pub fn start_mode(&self) -> GameMode {
+        GameMode::from_raw(self.start_mode_raw()).unwrap_or(GameMode::Cube)
+    }
+
+    /// Sets the gamemode the player starts in.
+    pub fn set_start_mode(&self, mode: GameMode) {
+        self.set_start_mode_raw(mode.raw())
+    }
 }
 
 impl_addr_funcs!(LevelSettings);
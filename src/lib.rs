@@ -1,29 +1,88 @@
+//! Geometry Dash modding library.
+//!
+//! The bindings to the running game (`PlayLayer`, `cocos2d`, hacks that patch
+//! the game...) are only built for 32-bit Windows with the `game` feature.
+//! Data formats, models and algorithms (`wav`, `clickbot`, `GameMode`,
+//! `GameObjectType`, `FixedStep`...) compile on any target, e.g. with
+//! `default-features = false`.
+
+pub mod checkpoints;
+pub mod clickbot;
+pub mod config;
+mod fps_bypass;
+mod game_mode;
+mod game_object_type;
+pub mod geometry;
+pub mod guard;
+pub mod hitbox;
+pub mod hook;
+pub mod input;
+pub mod inspector;
+pub mod logging;
+pub mod main_thread;
+pub mod modding;
+pub mod noclip;
+pub mod ring_buffer;
+pub mod rtti;
+mod speedhack;
+pub mod start_pos;
+pub mod status_labels;
+pub mod wav;
+
+#[cfg(feature = "fmod")]
+pub mod fmod;
+
+#[cfg(game_bindings)]
 pub mod cocos2d;
+#[cfg(game_bindings)]
 mod game_manager;
+#[cfg(game_bindings)]
 mod game_object;
+#[cfg(game_bindings)]
 mod level_settings;
+#[cfg(game_bindings)]
 mod play_layer;
+#[cfg(game_bindings)]
 mod player_object;
 
-#[cfg(feature = "fmod")]
-pub mod fmod;
-
-#[cfg(feature = "fmod")]
+#[cfg(all(game_bindings, feature = "fmod"))]
+mod audio_recorder;
+#[cfg(all(game_bindings, feature = "fmod"))]
 mod fmod_audio_engine;
 
-#[cfg(feature = "fmod")]
+#[cfg(all(game_bindings, feature = "fmod"))]
+pub use audio_recorder::*;
+#[cfg(all(game_bindings, feature = "fmod"))]
 pub use fmod_audio_engine::*;
 
+pub use fps_bypass::*;
+pub use game_mode::*;
+pub use game_object_type::*;
+pub use speedhack::*;
+
+#[cfg(game_bindings)]
 pub use game_manager::*;
+#[cfg(game_bindings)]
 pub use game_object::*;
+#[cfg(game_bindings)]
 pub use level_settings::*;
+#[cfg(game_bindings)]
 pub use play_layer::*;
+#[cfg(game_bindings)]
 pub use player_object::*;
+#[cfg(game_bindings)]
 pub use windows;
 
+/// Marks the function that creates the mod, see [`modding`].
+pub use geometrydash_macros::main;
+
 pub type Ptr = usize;
 
+/// Version of Geometry Dash the bindings are for.
+pub const GAME_VERSION: &str = "2.113";
+
 /// GetModuleHandle(NULL)
+#[cfg(game_bindings)]
 #[inline]
 pub fn get_base() -> Ptr {
     // unsafe { winapi::um::libloaderapi::GetModuleHandleA(std::ptr::null()) as Ptr }
@@ -42,12 +101,17 @@ pub const fn read_mem<T>(address: Ptr) -> *mut T {
 }
 
 /// Reads a pointer at a given address
+///
+/// # Safety
+///
+/// `address` must be valid for reading a pointer.
 #[inline]
 pub unsafe fn read_ptr(address: Ptr) -> Ptr {
     *read_mem(address)
 }
 
 /// Copies the given data to the given address in memory.
+#[cfg(game_bindings)]
 pub fn patch_mem(address: Ptr, data: &[u8]) -> windows::core::Result<()> {
     use windows::Win32::System::Diagnostics::Debug::WriteProcessMemory;
     use windows::Win32::System::Memory::{
@@ -115,6 +179,12 @@ macro_rules! impl_addr_funcs {
                 value.address
             }
         }
+
+        impl From<$crate::Ptr> for $t {
+            fn from(address: $crate::Ptr) -> $t {
+                Self { address }
+            }
+        }
     };
 }
 
@@ -0,0 +1,431 @@
+//! A [`log`] backend for mods.
+//!
+//! [`Logger`] writes every record to a log file in a `logs` folder next to the
+//! game, and optionally to a console window:
+//!
+//! ```no_run
+//! use geometrydash::logging::Logger;
+//!
+//! Logger::builder("my-mod")
+//!     .level(log::LevelFilter::Debug)
+//!     .console(true)
+//!     .init()
+//!     .unwrap();
+//! log::info!("loaded");
+//! ```
+//!
+//! Every session starts a new file (`my-mod.log`), the previous ones are kept
+//! as `my-mod.1.log`, `my-mod.2.log`... up to [`LoggerBuilder::max_files`]. A
+//! file that grows over [`LoggerBuilder::max_size`] is rotated the same way.
+//! Lines are written right away, so the end of the log survives a crash.
+//!
+//! [`LoggerBuilder::init`] also installs [`panic_hook`], which logs panics with
+//! their backtrace instead of printing them to a stderr nobody can see.
+
+use log::{LevelFilter, Log, Metadata, Record};
+use std::{
+    fmt,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::{Mutex, MutexGuard},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Formats a time as `YYYY-MM-DD hh:mm:ss.mmm` (UTC).
+pub fn timestamp(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let secs = since_epoch.as_secs();
+    let (days, secs_of_day) = ((secs / 86400) as i64, secs % 86400);
+
+    // days since 1970-01-01 to a civil date, from Howard Hinnant's `civil_from_days`
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02}.{:03}",
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+/// Formats a log line: `<time> <LEVEL> [<mod>] <target>: <message>`.
+pub fn format_line(time: SystemTime, mod_name: &str, record: &Record) -> String {
+    format!(
+        "{} {:<5} [{mod_name}] {}: {}\n",
+        timestamp(time),
+        record.level(),
+        record.target(),
+        record.args()
+    )
+}
+
+/// Returns the default folder for log files: `logs` next to the game's executable.
+pub fn log_dir() -> Option<PathBuf> {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.join("logs")))
+}
+
+/// A log file that is moved to `<name>.1.log` when it gets too big.
+#[derive(Debug)]
+pub struct RotatingFile {
+    dir: PathBuf,
+    name: String,
+    max_size: u64,
+    max_files: usize,
+    file: File,
+    size: u64,
+}
+
+impl RotatingFile {
+    /// Creates `<dir>/<name>.log`, rotating the file of the previous session.
+    ///
+    /// `max_files` is how many old files are kept besides the current one.
+    pub fn open(dir: &Path, name: &str, max_size: u64, max_files: usize) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        shift_files(dir, name, max_files)?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            name: name.to_string(),
+            max_size,
+            max_files,
+            file: create(&file_path(dir, name, 0))?,
+            size: 0,
+        })
+    }
+
+    /// Path of the current file (`index` 0) or of an old one.
+    pub fn path(&self, index: usize) -> PathBuf {
+        file_path(&self.dir, &self.name, index)
+    }
+
+    /// Current size of the file in bytes.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Shifts the old files by one, dropping the oldest, and starts a new file.
+    pub fn rotate(&mut self) -> io::Result<()> {
+        shift_files(&self.dir, &self.name, self.max_files)?;
+        self.file = create(&self.path(0))?;
+        self.size = 0;
+        Ok(())
+    }
+
+    /// Appends a line, rotating first if it would make the file bigger than the limit.
+    pub fn write_line(&mut self, line: &str) -> io::Result<()> {
+        if self.size > 0 && self.size + line.len() as u64 > self.max_size {
+            self.rotate()?;
+        }
+        self.file.write_all(line.as_bytes())?;
+        self.size += line.len() as u64;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn file_path(dir: &Path, name: &str, index: usize) -> PathBuf {
+    match index {
+        0 => dir.join(format!("{name}.log")),
+        _ => dir.join(format!("{name}.{index}.log")),
+    }
+}
+
+/// Renames `<name>.log` to `<name>.1.log`, `<name>.1.log` to `<name>.2.log`...
+/// deleting the file that would go past `max_files`.
+fn shift_files(dir: &Path, name: &str, max_files: usize) -> io::Result<()> {
+    let _ = fs::remove_file(file_path(dir, name, max_files));
+    for index in (0..max_files).rev() {
+        let from = file_path(dir, name, index);
+        if from.exists() {
+            fs::rename(from, file_path(dir, name, index + 1))?;
+        }
+    }
+    Ok(())
+}
+
+fn create(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// Error returned by [`LoggerBuilder::init`].
+#[derive(Debug)]
+pub enum LogError {
+    /// The log file couldn't be created.
+    Io(io::Error),
+    /// Another logger was already set.
+    SetLogger(log::SetLoggerError),
+}
+
+impl fmt::Display for LogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to create the log file: {e}"),
+            Self::SetLogger(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for LogError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::SetLogger(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for LogError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<log::SetLoggerError> for LogError {
+    fn from(e: log::SetLoggerError) -> Self {
+        Self::SetLogger(e)
+    }
+}
+
+/// Options of a [`Logger`], created by [`Logger::builder`].
+#[derive(Debug, Clone)]
+pub struct LoggerBuilder {
+    mod_name: String,
+    dir: Option<PathBuf>,
+    level: LevelFilter,
+    max_size: u64,
+    max_files: usize,
+    console: bool,
+}
+
+impl LoggerBuilder {
+    /// Folder of the log files, [`log_dir`] by default.
+    pub fn dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = Some(dir.into());
+        self
+    }
+
+    /// Most verbose level that is logged, `Info` by default.
+    pub fn level(mut self, level: LevelFilter) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Size in bytes after which the file is rotated, 4 MiB by default.
+    pub fn max_size(mut self, max_size: u64) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// How many old files are kept, 5 by default.
+    pub fn max_files(mut self, max_files: usize) -> Self {
+        self.max_files = max_files;
+        self
+    }
+
+    /// Whether records are also written to a console window (stderr outside
+    /// of the game), off by default.
+    pub fn console(mut self, console: bool) -> Self {
+        self.console = console;
+        self
+    }
+
+    /// Creates the logger and writes a header with the mod name, the crate
+    /// version and the game version the bindings are for.
+    pub fn build(self) -> Result<Logger, LogError> {
+        let dir = self
+            .dir
+            .or_else(log_dir)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no log folder"))?;
+        let mut file = RotatingFile::open(&dir, &self.mod_name, self.max_size, self.max_files)?;
+        let header = format!(
+            "{} {} loaded, geometrydash {}, Geometry Dash {}\n",
+            timestamp(SystemTime::now()),
+            self.mod_name,
+            env!("CARGO_PKG_VERSION"),
+            crate::GAME_VERSION
+        );
+        file.write_line(&header)?;
+        if self.console {
+            open_console();
+            eprint!("{header}");
+        }
+        Ok(Logger {
+            mod_name: self.mod_name,
+            level: self.level,
+            console: self.console,
+            file: Mutex::new(file),
+        })
+    }
+
+    /// Builds the logger, sets it as the global [`log`] logger and installs
+    /// [`panic_hook`].
+    pub fn init(self) -> Result<(), LogError> {
+        let level = self.level;
+        log::set_boxed_logger(Box::new(self.build()?))?;
+        log::set_max_level(level);
+        std::panic::set_hook(Box::new(panic_hook));
+        Ok(())
+    }
+}
+
+/// A [`Log`] implementation writing to a [`RotatingFile`] and a console.
+#[derive(Debug)]
+pub struct Logger {
+    mod_name: String,
+    level: LevelFilter,
+    console: bool,
+    file: Mutex<RotatingFile>,
+}
+
+impl Logger {
+    pub fn builder(mod_name: &str) -> LoggerBuilder {
+        LoggerBuilder {
+            mod_name: mod_name.to_string(),
+            dir: None,
+            level: LevelFilter::Info,
+            max_size: 4 * 1024 * 1024,
+            max_files: 5,
+            console: false,
+        }
+    }
+
+    #[inline]
+    pub fn mod_name(&self) -> &str {
+        &self.mod_name
+    }
+
+    fn file(&self) -> MutexGuard<'_, RotatingFile> {
+        self.file.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let line = format_line(SystemTime::now(), &self.mod_name, record);
+        // nowhere to report a failed write to
+        let _ = self.file().write_line(&line);
+        if self.console {
+            eprint!("{line}");
+        }
+    }
+
+    fn flush(&self) {
+        let _ = self.file().flush();
+    }
+}
+
+/// Panic hook that logs the message, location and backtrace of a panic as an
+/// error with the `panic` target.
+pub fn panic_hook(info: &std::panic::PanicHookInfo) {
+    let message = info
+        .payload()
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| info.payload().downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("Box<dyn Any>");
+    let location = info
+        .location()
+        .map_or_else(String::new, |l| format!(" at {l}"));
+    let thread = std::thread::current();
+    log::error!(
+        target: "panic",
+        "thread '{}' panicked{location}: {message}\n{}",
+        thread.name().unwrap_or("<unnamed>"),
+        std::backtrace::Backtrace::force_capture()
+    );
+    log::logger().flush();
+}
+
+/// Opens a console window for the game process, so that stderr goes somewhere.
+#[cfg(game_bindings)]
+fn open_console() {
+    // fails if the process already has a console, which is fine
+    let _ = unsafe { windows::Win32::System::Console::AllocConsole() };
+}
+
+#[cfg(not(game_bindings))]
+fn open_console() {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "geometrydash-logging-{name}-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn formats_lines() {
+        assert_eq!(timestamp(UNIX_EPOCH), "1970-01-01 00:00:00.000");
+        let time = UNIX_EPOCH + Duration::from_millis(1_709_210_096_789);
+        assert_eq!(timestamp(time), "2024-02-29 12:34:56.789");
+
+        let line = format_line(
+            time,
+            "noclip",
+            &Record::builder()
+                .level(log::Level::Warn)
+                .target("noclip::hooks")
+                .args(format_args!("died {} times", 3))
+                .build(),
+        );
+        assert_eq!(
+            line,
+            "2024-02-29 12:34:56.789 WARN  [noclip] noclip::hooks: died 3 times\n"
+        );
+    }
+
+    #[test]
+    fn rotates_files() {
+        let dir = temp_dir("rotate");
+        let read = |file: &RotatingFile, index| fs::read_to_string(file.path(index)).ok();
+
+        let mut file = RotatingFile::open(&dir, "mod", 10, 2).unwrap();
+        file.write_line("first\n").unwrap();
+        file.write_line("second\n").unwrap();
+        assert_eq!(read(&file, 0).as_deref(), Some("second\n"));
+        assert_eq!(read(&file, 1).as_deref(), Some("first\n"));
+        assert_eq!(file.size(), 7);
+
+        // a new session keeps `max_files` old files
+        drop(file);
+        let file = RotatingFile::open(&dir, "mod", 10, 2).unwrap();
+        assert_eq!(read(&file, 0).as_deref(), Some(""));
+        assert_eq!(read(&file, 1).as_deref(), Some("second\n"));
+        assert_eq!(read(&file, 2).as_deref(), Some("first\n"));
+        drop(file);
+        let file = RotatingFile::open(&dir, "mod", 10, 2).unwrap();
+        assert_eq!(read(&file, 2).as_deref(), Some("second\n"));
+        assert!(!file.path(3).exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,389 @@
+//! Running code on the game's main thread.
+//!
+//! cocos2d isn't thread-safe: nodes can only be created and levels restarted
+//! from the thread that runs the game loop. [`TaskQueue`] holds closures sent
+//! from other threads until the main thread drains it. In the game, `install`
+//! schedules a selector on `CCDirector::scheduler()` that drains the global
+//! queue every frame, and `run_on_main_thread`/`block_on_main` push to it.
+//! The global queue is closed until `install` (and after `uninstall`), so
+//! those closures are dropped instead of waiting forever.
+
+use std::{
+    collections::VecDeque,
+    fmt,
+    sync::{mpsc, Mutex, MutexGuard},
+    thread::{self, ThreadId},
+};
+
+type Task = Box<dyn FnOnce() + Send>;
+
+/// Error returned by [`TaskQueue::block_on`] when the closure didn't run to
+/// completion: the queue was closed or the closure panicked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Canceled;
+
+impl fmt::Display for Canceled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the task was canceled before it completed")
+    }
+}
+
+impl std::error::Error for Canceled {}
+
+struct State {
+    tasks: VecDeque<Task>,
+    main_thread: Option<ThreadId>,
+    closed: bool,
+}
+
+/// A queue of closures that run, in order, on the thread that drains it.
+pub struct TaskQueue {
+    state: Mutex<State>,
+}
+
+impl Default for TaskQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for TaskQueue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.lock();
+        f.debug_struct("TaskQueue")
+            .field("len", &state.tasks.len())
+            .field("main_thread", &state.main_thread)
+            .field("closed", &state.closed)
+            .finish()
+    }
+}
+
+impl TaskQueue {
+    pub const fn new() -> Self {
+        Self {
+            state: Mutex::new(State {
+                tasks: VecDeque::new(),
+                main_thread: None,
+                closed: false,
+            }),
+        }
+    }
+
+    /// Creates a queue that drops closures until [`TaskQueue::reopen`].
+    pub const fn closed() -> Self {
+        Self {
+            state: Mutex::new(State {
+                tasks: VecDeque::new(),
+                main_thread: None,
+                closed: true,
+            }),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Number of closures waiting to run.
+    pub fn len(&self) -> usize {
+        self.lock().tasks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether the current thread is the one that drains the queue.
+    ///
+    /// It is unknown (and this returns `false`) until the first
+    /// [`TaskQueue::drain`] or [`TaskQueue::set_main_thread`].
+    pub fn is_main_thread(&self) -> bool {
+        self.lock().main_thread == Some(thread::current().id())
+    }
+
+    /// Makes the current thread the main thread before it drains the queue.
+    pub fn set_main_thread(&self) {
+        self.lock().main_thread = Some(thread::current().id());
+    }
+
+    /// Queues a closure. It is dropped without running if the queue is closed.
+    pub fn push<F: FnOnce() + Send + 'static>(&self, task: F) {
+        let mut state = self.lock();
+        if !state.closed {
+            state.tasks.push_back(Box::new(task));
+        }
+    }
+
+    /// Runs a closure on the main thread and waits for its result.
+    ///
+    /// On the main thread itself the closure runs right away, since waiting
+    /// for the next drain would never return. On other threads, a closed
+    /// queue returns [`Canceled`] right away. While the main thread is
+    /// unknown, calling it from the thread that will drain the queue never
+    /// returns.
+    pub fn block_on<R, F>(&self, task: F) -> Result<R, Canceled>
+    where
+        R: Send + 'static,
+        F: FnOnce() -> R + Send + 'static,
+    {
+        if self.is_main_thread() {
+            return Ok(task());
+        }
+        let (sender, receiver) = mpsc::sync_channel(1);
+        self.push(move || {
+            let _ = sender.send(task());
+        });
+        // the sender is dropped without sending if the queue is closed or the task panics
+        receiver.recv().map_err(|_| Canceled)
+    }
+
+    /// Runs the closures that were queued before the call, in order, on the
+    /// current thread, which becomes the main thread. Returns how many ran.
+    ///
+    /// Closures queued while draining run on the next drain, so a closure that
+    /// queues itself again can't keep the game loop busy. A closure that
+    /// panics or crashes (see [`crate::guard::catch`]) is logged and doesn't
+    /// stop the others.
+    pub fn drain(&self) -> usize {
+        let count = {
+            let mut state = self.lock();
+            state.main_thread = Some(thread::current().id());
+            state.tasks.len()
+        };
+        let mut ran = 0;
+        for _ in 0..count {
+            // don't hold the lock while running, the task may queue more tasks
+            let Some(task) = self.lock().tasks.pop_front() else {
+                break;
+            };
+            if let Err(error) = crate::guard::catch(task) {
+                crate::guard::discard_details();
+                log::error!("main thread task {error}");
+            }
+            ran += 1;
+        }
+        ran
+    }
+
+    /// Drops the queued closures and makes the queue drop new ones, waking up
+    /// every [`TaskQueue::block_on`] with [`Canceled`].
+    pub fn close(&self) {
+        let tasks = {
+            let mut state = self.lock();
+            state.closed = true;
+            std::mem::take(&mut state.tasks)
+        };
+        drop(tasks);
+    }
+
+    /// Accepts closures again after [`TaskQueue::close`].
+    pub fn reopen(&self) {
+        self.lock().closed = false;
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.lock().closed
+    }
+}
+
+#[cfg(game_bindings)]
+mod scheduler {
+    use super::*;
+    use crate::{
+        cocos2d::{CCDirector, CCNode, SelSchedule},
+        AddressUtils, Ptr,
+    };
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Once,
+    };
+
+    static QUEUE: TaskQueue = TaskQueue::closed();
+    /// Node the selector is scheduled on, retained while installed.
+    static TARGET: AtomicUsize = AtomicUsize::new(0);
+    static CLEANUP: Once = Once::new();
+
+    unsafe extern "thiscall" fn drain_queue(_target: Ptr, _dt: f32) {
+        QUEUE.drain();
+    }
+
+    /// Returns the queue drained by the scheduled selector.
+    pub fn queue() -> &'static TaskQueue {
+        &QUEUE
+    }
+
+    /// Schedules the selector that drains the queue every frame. Must be called
+    /// from the main thread, e.g. in [`crate::modding::Mod::on_load`]. It is
+    /// unscheduled when the mod is unloaded.
+    pub fn install() {
+        if TARGET.load(Ordering::Relaxed) != 0 {
+            return;
+        }
+        let target = CCNode::create();
+        if target.is_null() {
+            return;
+        }
+        target.retain();
+        TARGET.store(target.ptr(), Ordering::Relaxed);
+        QUEUE.set_main_thread();
+        QUEUE.reopen();
+        CCDirector::shared().scheduler().schedule_selector(
+            drain_queue as SelSchedule,
+            target.ptr(),
+            0.0,
+            false,
+        );
+        CLEANUP.call_once(|| crate::modding::on_cleanup(uninstall));
+    }
+
+    /// Unschedules the selector and cancels the queued closures. Must be called
+    /// from the main thread.
+    pub fn uninstall() {
+        let target = TARGET.swap(0, Ordering::Relaxed);
+        if target == 0 {
+            return;
+        }
+        CCDirector::shared()
+            .scheduler()
+            .unschedule_selector(drain_queue as SelSchedule, target);
+        CCNode::from_address(target).release();
+        QUEUE.close();
+    }
+
+    /// Runs a closure on the main thread during the next frame. It is dropped
+    /// if the queue isn't installed.
+    pub fn run_on_main_thread<F: FnOnce() + Send + 'static>(task: F) {
+        QUEUE.push(task);
+    }
+
+    /// Runs a closure on the main thread and waits for its result, see
+    /// [`TaskQueue::block_on`]. Returns [`Canceled`] if the queue isn't
+    /// installed.
+    ///
+    /// ```no_run
+    /// use geometrydash::{main_thread, AddressUtils, GameManager};
+    ///
+    /// std::thread::spawn(|| {
+    ///     let time = main_thread::block_on_main(|| {
+    ///         let play_layer = GameManager::shared().play_layer().to_option()?;
+    ///         play_layer.reset_level();
+    ///         Some(play_layer.time())
+    ///     });
+    /// });
+    /// ```
+    pub fn block_on_main<R, F>(task: F) -> Result<R, Canceled>
+    where
+        R: Send + 'static,
+        F: FnOnce() -> R + Send + 'static,
+    {
+        QUEUE.block_on(task)
+    }
+}
+
+#[cfg(game_bindings)]
+pub use scheduler::{block_on_main, install, queue, run_on_main_thread, uninstall};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn runs_in_order() {
+        let queue = TaskQueue::new();
+        let order = Arc::new(Mutex::new(Vec::new()));
+        for i in 0..5 {
+            let order = order.clone();
+            queue.push(move || order.lock().unwrap().push(i));
+        }
+        assert_eq!(queue.len(), 5);
+        assert_eq!(queue.drain(), 5);
+        assert!(queue.is_empty());
+        assert_eq!(*order.lock().unwrap(), [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn tasks_queued_while_draining_wait() {
+        let queue = Arc::new(TaskQueue::new());
+        let inner = queue.clone();
+        queue.push(move || inner.push(|| {}));
+        assert_eq!(queue.drain(), 1);
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.drain(), 1);
+        assert_eq!(queue.drain(), 0);
+    }
+
+    #[test]
+    fn panics_dont_stop_the_drain() {
+        let queue = TaskQueue::new();
+        let ran = Arc::new(Mutex::new(false));
+        queue.push(|| panic!("task panicked"));
+        let flag = ran.clone();
+        queue.push(move || *flag.lock().unwrap() = true);
+        assert_eq!(queue.drain(), 2);
+        assert!(*ran.lock().unwrap());
+    }
+
+    #[test]
+    fn block_on_wakes_up_with_the_result() {
+        let queue = Arc::new(TaskQueue::new());
+        // becomes the main thread
+        queue.drain();
+        assert!(queue.is_main_thread());
+        assert_eq!(queue.block_on(|| 1 + 1), Ok(2));
+
+        let worker = {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                assert!(!queue.is_main_thread());
+                queue.block_on(|| thread::current().id())
+            })
+        };
+        while queue.is_empty() {
+            thread::yield_now();
+        }
+        queue.drain();
+        assert_eq!(worker.join().unwrap(), Ok(thread::current().id()));
+    }
+
+    #[test]
+    fn closed_until_installed() {
+        let queue = Arc::new(TaskQueue::closed());
+        assert!(queue.is_closed());
+        // workers don't wait for a queue nobody drains
+        let worker = {
+            let queue = queue.clone();
+            thread::spawn(move || queue.block_on(|| 1))
+        };
+        assert_eq!(worker.join().unwrap(), Err(Canceled));
+
+        // the main thread is known before the first drain
+        queue.set_main_thread();
+        queue.reopen();
+        assert_eq!(queue.block_on(|| 2), Ok(2));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn closing_cancels_waiters() {
+        let queue = Arc::new(TaskQueue::new());
+        queue.drain();
+        let worker = {
+            let queue = queue.clone();
+            thread::spawn(move || queue.block_on(|| 1))
+        };
+        while queue.is_empty() {
+            thread::yield_now();
+        }
+        queue.close();
+        assert_eq!(worker.join().unwrap(), Err(Canceled));
+
+        // closed queues drop new tasks
+        assert_eq!(queue.block_on(|| 1), Ok(1)); // still inline on the main thread
+        queue.push(|| {});
+        assert!(queue.is_empty());
+        queue.reopen();
+        queue.push(|| {});
+        assert_eq!(queue.len(), 1);
+    }
+}
@@ -0,0 +1,390 @@
+//! Mod lifecycle.
+//!
+//! A mod implements [`Mod`] and marks the function that creates it with
+//! `#[geometrydash::main]`, which generates the `DllMain` of the DLL:
+//!
+//! ```ignore
+//! use geometrydash::{modding::Mod, PlayLayer};
+//!
+//! #[derive(Default)]
+//! struct Attempts(u32);
+//!
+//! impl Mod for Attempts {
+//!     fn on_level_enter(&mut self, _play_layer: PlayLayer) {
+//!         self.0 += 1;
+//!     }
+//! }
+//!
+//! #[geometrydash::main]
+//! fn main() -> Attempts {
+//!     Attempts::default()
+//! }
+//! ```
+//!
+//! `DllMain` runs under the loader lock, so it only starts a thread that waits
+//! for the game to be ready and hooks `CCScheduler::update`. Everything else,
+//! including creating the mod, happens on the game's main thread:
+//!
+//! 1. the entry point is called and [`Mod::on_load`] runs on the first frame,
+//! 2. [`Mod::on_tick`] runs every frame, [`Mod::on_level_enter`] and
+//!    [`Mod::on_level_exit`] when the `PlayLayer` changes,
+//! 3. after [`unload`], [`Mod::on_unload`] runs on the next frame, then the
+//!    patches and hooks registered with [`patch`] and [`on_cleanup`] are
+//!    reverted (newest first) and the DLL frees itself once that frame is
+//!    over (unless the mod crashed, see [`disable`]). The hooks of this crate (`input::install`, `noclip::install`,
+//!    `main_thread::install`) register themselves.
+//!
+//! Every callback runs inside [`crate::guard::call`]: if one panics or
+//! crashes, a crash report is written and the mod is [`disable`]d.
+
+use crate::Ptr;
+
+/// A change of the current level, see [`LevelTracker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LevelEvent {
+    /// A `PlayLayer` was entered.
+    Enter(Ptr),
+    /// The `PlayLayer` was left.
+    Exit(Ptr),
+}
+
+/// Turns the address of the current `PlayLayer`, sampled every frame, into
+/// level enter and exit events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LevelTracker {
+    current: Ptr,
+}
+
+impl LevelTracker {
+    pub const fn new() -> Self {
+        Self { current: 0 }
+    }
+
+    /// Address of the `PlayLayer` the player is in, 0 outside of levels.
+    #[inline]
+    pub fn current(&self) -> Ptr {
+        self.current
+    }
+
+    /// Updates the current `PlayLayer` (0 if there is none).
+    ///
+    /// Going from one `PlayLayer` straight to another exits the first one
+    /// before entering the second.
+    pub fn update(&mut self, play_layer: Ptr) -> impl Iterator<Item = LevelEvent> {
+        let previous = std::mem::replace(&mut self.current, play_layer);
+        let changed = previous != play_layer;
+        let exit = (changed && previous != 0).then_some(LevelEvent::Exit(previous));
+        let enter = (changed && play_layer != 0).then_some(LevelEvent::Enter(play_layer));
+        exit.into_iter().chain(enter)
+    }
+}
+
+/// Actions that undo what a mod did, run newest first.
+#[derive(Default)]
+pub struct CleanupStack {
+    actions: Vec<Box<dyn FnOnce() + Send>>,
+}
+
+impl std::fmt::Debug for CleanupStack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CleanupStack")
+            .field("len", &self.actions.len())
+            .finish()
+    }
+}
+
+impl CleanupStack {
+    pub const fn new() -> Self {
+        Self {
+            actions: Vec::new(),
+        }
+    }
+
+    pub fn push<F: FnOnce() + Send + 'static>(&mut self, action: F) {
+        self.actions.push(Box::new(action));
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Runs every action, newest first, and empties the stack.
+    pub fn run(&mut self) {
+        while let Some(action) = self.actions.pop() {
+            action();
+        }
+    }
+}
+
+#[cfg(game_bindings)]
+mod runtime {
+    use super::*;
+    use crate::{
+        cocos2d::{get_hmod, CCDirector},
+        get_base, guard,
+        hook::Hook,
+        read_ptr, AddressUtils, GameManager, PlayLayer,
+    };
+    use std::sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex, MutexGuard, OnceLock,
+    };
+    use windows::Win32::{
+        Foundation::HMODULE,
+        System::LibraryLoader::{DisableThreadLibraryCalls, FreeLibraryAndExitThread},
+    };
+
+    /// Callbacks of a mod. They all run on the game's main thread.
+    pub trait Mod: Send + 'static {
+        /// Called on the first frame after the mod was created.
+        fn on_load(&mut self) {}
+
+        /// Called before the mod is unloaded, see [`unload`].
+        fn on_unload(&mut self) {}
+
+        /// Called when a level is entered.
+        fn on_level_enter(&mut self, _play_layer: PlayLayer) {}
+
+        /// Called when a level is left.
+        fn on_level_exit(&mut self) {}
+
+        /// Called every frame with the frame time in seconds.
+        fn on_tick(&mut self, _dt: f32) {}
+    }
+
+    type Init = Box<dyn FnOnce() -> Box<dyn Mod> + Send>;
+    type SchedulerUpdate = extern "thiscall" fn(Ptr, f32);
+
+    struct Runtime {
+        instance: Box<dyn Mod>,
+        tracker: LevelTracker,
+    }
+
+    static MODULE: AtomicUsize = AtomicUsize::new(0);
+    static INIT: Mutex<Option<Init>> = Mutex::new(None);
+    static RUNTIME: Mutex<Option<Runtime>> = Mutex::new(None);
+    static CLEANUP: Mutex<CleanupStack> = Mutex::new(CleanupStack::new());
+    static UNLOAD: AtomicBool = AtomicBool::new(false);
+    static CRASHED: AtomicBool = AtomicBool::new(false);
+    static NAME: OnceLock<&'static str> = OnceLock::new();
+    static HOOK: OnceLock<Hook> = OnceLock::new();
+
+    // GameManager* Global_GameManager = base + 0x3222D0
+    const GAME_MANAGER: Ptr = 0x3222D0;
+
+    fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+        mutex.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// `DllMain` of a mod, called by the code `#[geometrydash::main]` generates.
+    #[doc(hidden)]
+    pub fn dll_main<M: Mod>(module: Ptr, reason: u32, name: &'static str, init: fn() -> M) -> i32 {
+        const DLL_PROCESS_ATTACH: u32 = 1;
+
+        if reason == DLL_PROCESS_ATTACH {
+            MODULE.store(module, Ordering::Relaxed);
+            let _ = NAME.set(name);
+            unsafe {
+                let _ = DisableThreadLibraryCalls(HMODULE(module as _));
+            }
+            *lock(&INIT) = Some(Box::new(move || Box::new(init()) as Box<dyn Mod>));
+            // the thread only starts once the loader lock is released
+            std::thread::spawn(wait_for_game);
+        }
+        1
+    }
+
+    /// Waits for the game to have a scene running, then hooks the scheduler.
+    fn wait_for_game() {
+        while unsafe { read_ptr(get_base() + GAME_MANAGER) } == 0
+            || CCDirector::shared().running_scene().is_null()
+        {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+        guard::install();
+        let target = unsafe {
+            windows::Win32::System::LibraryLoader::GetProcAddress(
+                get_hmod(),
+                windows::core::s!("?update@CCScheduler@cocos2d@@UAEXM@Z"),
+            )
+        }
+        .map_or(0, |f| f as Ptr);
+        match Hook::new(target, scheduler_update as SchedulerUpdate as Ptr) {
+            Ok(hook) => {
+                let hook = HOOK.get_or_init(|| hook);
+                let _ = hook.enable();
+            }
+            Err(e) => log::error!("can't hook CCScheduler::update: {e}"),
+        }
+    }
+
+    extern "thiscall" fn scheduler_update(this: Ptr, dt: f32) {
+        let original: SchedulerUpdate =
+            unsafe { std::mem::transmute(HOOK.get().unwrap().trampoline()) };
+        original(this, dt);
+        tick(dt);
+    }
+
+    fn tick(dt: f32) {
+        let mut runtime = lock(&RUNTIME);
+        if UNLOAD.load(Ordering::Relaxed) {
+            if let Some(mut state) = runtime.take() {
+                if CRASHED.load(Ordering::Relaxed) {
+                    // its state may be broken, don't run any more of its code
+                    std::mem::forget(state);
+                } else {
+                    guard::call("Mod::on_unload", || state.instance.on_unload());
+                }
+            }
+            drop(runtime);
+            finish_unload();
+            return;
+        }
+
+        if runtime.is_none() {
+            let Some(init) = lock(&INIT).take() else {
+                return;
+            };
+            let Some(mut instance) = guard::call("main", init) else {
+                return;
+            };
+            if guard::call("Mod::on_load", || instance.on_load()).is_none() {
+                std::mem::forget(instance);
+                return;
+            }
+            // after `on_load` so that it runs before the hook of a logger set up there
+            guard::install_panic_hook();
+            *runtime = Some(Runtime {
+                instance,
+                tracker: LevelTracker::new(),
+            });
+        }
+        let Some(state) = runtime.as_mut() else {
+            return;
+        };
+
+        let play_layer = GameManager::shared().play_layer();
+        for event in state.tracker.update(play_layer.ptr()) {
+            let ok = match event {
+                LevelEvent::Enter(address) => guard::call("Mod::on_level_enter", || {
+                    state
+                        .instance
+                        .on_level_enter(PlayLayer::from_address(address))
+                }),
+                LevelEvent::Exit(_) => {
+                    guard::call("Mod::on_level_exit", || state.instance.on_level_exit())
+                }
+            };
+            if ok.is_none() {
+                return;
+            }
+        }
+        guard::call("Mod::on_tick", || state.instance.on_tick(dt));
+    }
+
+    fn finish_unload() {
+        guard::call("cleanup", || lock(&CLEANUP).run());
+        if let Some(hook) = HOOK.get() {
+            let _ = hook.disable();
+        }
+        guard::uninstall();
+        let module = MODULE.load(Ordering::Relaxed);
+        // the state of a crashed mod was leaked, and what it owns (an attached
+        // audio recorder, a speedhack...) may still call into the DLL
+        if module != 0 && !CRASHED.load(Ordering::Relaxed) {
+            // the main thread is still in the detour, it has left it once the
+            // frame is drawn (and with the hook disabled it won't come back)
+            let director = CCDirector::shared();
+            let frame = director.total_frames();
+            std::thread::spawn(move || {
+                while director.total_frames() == frame {
+                    std::thread::sleep(std::time::Duration::from_millis(5));
+                }
+                unsafe { FreeLibraryAndExitThread(HMODULE(module as _), 0) }
+            });
+        }
+    }
+
+    /// Asks for the mod to be unloaded on the next frame. Can be called from any thread.
+    pub fn unload() {
+        UNLOAD.store(true, Ordering::Relaxed);
+    }
+
+    /// Unloads the mod on the next frame without calling [`Mod::on_unload`],
+    /// after it crashed. The cleanup actions still run, but the DLL stays
+    /// loaded since the mod's state isn't dropped.
+    pub fn disable() {
+        CRASHED.store(true, Ordering::Relaxed);
+        UNLOAD.store(true, Ordering::Relaxed);
+    }
+
+    /// Name of the mod's crate, `None` before `DllMain` ran.
+    pub fn name() -> Option<String> {
+        NAME.get().map(|name| name.to_string())
+    }
+
+    /// Registers an action that runs when the mod is unloaded, e.g. to disable a hook.
+    pub fn on_cleanup<F: FnOnce() + Send + 'static>(action: F) {
+        lock(&CLEANUP).push(action);
+    }
+
+    /// Like [`crate::patch_mem`], but the original bytes are restored when the mod is unloaded.
+    pub fn patch(address: Ptr, data: &[u8]) -> windows::core::Result<()> {
+        let original =
+            unsafe { std::slice::from_raw_parts(address as *const u8, data.len()) }.to_vec();
+        crate::patch_mem(address, data)?;
+        on_cleanup(move || {
+            let _ = crate::patch_mem(address, &original);
+        });
+        Ok(())
+    }
+}
+
+#[cfg(game_bindings)]
+pub use runtime::{disable, dll_main, name, on_cleanup, patch, unload, Mod};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn level_events() {
+        let mut tracker = LevelTracker::new();
+        assert_eq!(tracker.update(0).count(), 0);
+        assert_eq!(
+            tracker.update(0x1000).collect::<Vec<_>>(),
+            [LevelEvent::Enter(0x1000)]
+        );
+        assert_eq!(tracker.update(0x1000).count(), 0);
+        assert_eq!(
+            tracker.update(0x2000).collect::<Vec<_>>(),
+            [LevelEvent::Exit(0x1000), LevelEvent::Enter(0x2000)]
+        );
+        assert_eq!(
+            tracker.update(0).collect::<Vec<_>>(),
+            [LevelEvent::Exit(0x2000)]
+        );
+        assert_eq!(tracker.current(), 0);
+    }
+
+    #[test]
+    fn cleanup_runs_newest_first() {
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let mut stack = CleanupStack::new();
+        for i in 0..3 {
+            let order = order.clone();
+            stack.push(move || order.lock().unwrap().push(i));
+        }
+        assert_eq!(stack.len(), 3);
+        stack.run();
+        assert!(stack.is_empty());
+        assert_eq!(*order.lock().unwrap(), [2, 1, 0]);
+    }
+}
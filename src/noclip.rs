@@ -0,0 +1,332 @@
+//! Noclip: players go through hazards instead of dying.
+//!
+//! [`Noclip`] decides which deaths are ignored and keeps [`NoclipStats`] about
+//! them. In the game, `install` hooks `PlayLayer::destroyPlayer` to skip the
+//! death and `PlayLayer::update` to count the frames:
+//!
+//! ```ignore
+//! use geometrydash::noclip::{self, Players};
+//!
+//! noclip::install().unwrap();
+//! noclip::noclip().set_players(Players::Both);
+//! noclip::noclip().set_enabled(true);
+//!
+//! // later, e.g. in a status label
+//! let stats = noclip::noclip().stats();
+//! println!("{:.2}% accuracy, {} deaths", stats.accuracy(), stats.deaths);
+//! ```
+//!
+//! The stats are reset when a new attempt starts, i.e. when the attempt
+//! counter of the level changes. Respawning at a practice mode checkpoint
+//! continues the attempt.
+
+/// One of the two players of a level (the second one exists in dual mode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Player {
+    One,
+    Two,
+}
+
+/// Which players noclip applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Players {
+    #[default]
+    One,
+    Two,
+    Both,
+}
+
+impl Players {
+    /// Returns whether `player` is one of these players.
+    #[inline]
+    pub fn contains(self, player: Player) -> bool {
+        matches!(
+            (self, player),
+            (Self::Both, _) | (Self::One, Player::One) | (Self::Two, Player::Two)
+        )
+    }
+}
+
+/// Deaths noclip prevented during the current attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct NoclipStats {
+    /// Frames played with noclip enabled.
+    pub frames: u32,
+    /// Frames in which a player would have died.
+    pub death_frames: u32,
+    /// Times a player would have died, touching hazards on consecutive frames
+    /// counts as a single death.
+    pub deaths: u32,
+    /// Percentage of the level at which a player would have died first.
+    pub first_death_percent: Option<f32>,
+}
+
+impl NoclipStats {
+    /// Percentage of the frames in which no player would have died, 100 if
+    /// nothing was played yet.
+    pub fn accuracy(&self) -> f32 {
+        if self.frames == 0 {
+            return 100.0;
+        }
+        (self.frames - self.death_frames) as f32 / self.frames as f32 * 100.0
+    }
+}
+
+/// Noclip settings and the stats of the current attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Noclip {
+    enabled: bool,
+    players: Players,
+    stats: NoclipStats,
+    /// Whether a death was prevented since the last frame ended.
+    dying: bool,
+    /// Whether a death was prevented in the previous frame.
+    was_dying: bool,
+    /// Attempt of the previous frame.
+    attempt: Option<i32>,
+}
+
+impl Noclip {
+    pub const fn new() -> Self {
+        Self {
+            enabled: false,
+            players: Players::One,
+            stats: NoclipStats {
+                frames: 0,
+                death_frames: 0,
+                deaths: 0,
+                first_death_percent: None,
+            },
+            dying: false,
+            was_dying: false,
+            attempt: None,
+        }
+    }
+
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    pub fn toggle(&mut self) {
+        self.enabled = !self.enabled;
+    }
+
+    #[inline]
+    pub fn players(&self) -> Players {
+        self.players
+    }
+
+    pub fn set_players(&mut self, players: Players) {
+        self.players = players;
+    }
+
+    /// Stats of the current attempt.
+    #[inline]
+    pub fn stats(&self) -> NoclipStats {
+        self.stats
+    }
+
+    /// The attempt the stats are about, `None` before the first frame.
+    #[inline]
+    pub fn attempt(&self) -> Option<i32> {
+        self.attempt
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.stats = NoclipStats::default();
+        self.dying = false;
+        self.was_dying = false;
+    }
+
+    /// Called when `player` is about to die. Returns whether the death should
+    /// be skipped, in which case it is counted at the end of the frame.
+    pub fn on_death(&mut self, player: Player) -> bool {
+        let skip = self.enabled && self.players.contains(player);
+        self.dying |= skip;
+        skip
+    }
+
+    /// Called after every frame of the level with the attempt counter and how
+    /// far the player is (in percent). An attempt different from the previous
+    /// frame's starts a new attempt.
+    pub fn end_frame(&mut self, attempt: i32, percent: f32) {
+        if self.attempt.is_some_and(|last| last != attempt) {
+            self.reset_stats();
+        }
+        self.attempt = Some(attempt);
+        if !self.enabled {
+            self.dying = false;
+            self.was_dying = false;
+            return;
+        }
+
+        let stats = &mut self.stats;
+        stats.frames += 1;
+        if self.dying {
+            stats.death_frames += 1;
+            if !self.was_dying {
+                stats.deaths += 1;
+            }
+            stats.first_death_percent.get_or_insert(percent);
+        }
+        self.was_dying = std::mem::take(&mut self.dying);
+    }
+}
+
+#[cfg(game_bindings)]
+mod hook {
+    use super::*;
+    use crate::{
+        get_base, guard,
+        hook::{Hook, HookError},
+        AddressUtils, PlayLayer, Ptr,
+    };
+    use std::sync::{Mutex, MutexGuard, Once, OnceLock};
+
+    static NOCLIP: Mutex<Noclip> = Mutex::new(Noclip::new());
+    static DESTROY_PLAYER: OnceLock<Hook> = OnceLock::new();
+    static UPDATE: OnceLock<Hook> = OnceLock::new();
+    static CLEANUP: Once = Once::new();
+
+    // PlayLayer::destroyPlayer = base + 0x20A1A0
+    // PlayLayer::update = base + 0x2029C0
+    const DESTROY_PLAYER_ADDR: Ptr = 0x20A1A0;
+    const UPDATE_ADDR: Ptr = 0x2029C0;
+
+    /// Returns the noclip state used by the hooks.
+    pub fn noclip() -> MutexGuard<'static, Noclip> {
+        NOCLIP.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Hooks `PlayLayer::destroyPlayer` and `PlayLayer::update`. Noclip
+    /// itself stays off until [`Noclip::set_enabled`]. The hooks are removed
+    /// when the mod is unloaded.
+    pub fn install() -> Result<(), HookError> {
+        CLEANUP.call_once(|| {
+            crate::modding::on_cleanup(|| {
+                let _ = uninstall();
+            })
+        });
+        let hook = |lock: &'static OnceLock<Hook>, target, detour| -> Result<(), HookError> {
+            if lock.get().is_none() {
+                let hook = Hook::new(get_base() + target, detour)?;
+                let _ = lock.set(hook);
+            }
+            lock.get().unwrap().enable()
+        };
+        hook(
+            &DESTROY_PLAYER,
+            DESTROY_PLAYER_ADDR,
+            destroy_player as DestroyPlayer as Ptr,
+        )?;
+        hook(&UPDATE, UPDATE_ADDR, update as Update as Ptr)
+    }
+
+    /// Removes the hooks, players die again.
+    pub fn uninstall() -> Result<(), HookError> {
+        for hook in [DESTROY_PLAYER.get(), UPDATE.get()].into_iter().flatten() {
+            hook.disable()?;
+        }
+        Ok(())
+    }
+
+    type DestroyPlayer = extern "fastcall" fn(Ptr, Ptr, Ptr, Ptr);
+    type Update = extern "fastcall" fn(Ptr, Ptr, f32);
+
+    extern "fastcall" fn destroy_player(this: Ptr, edx: Ptr, player: Ptr, object: Ptr) {
+        let skip = guard::call("noclip", || {
+            let play_layer = PlayLayer::from_address(this);
+            let which = if player == play_layer.player2().ptr() {
+                Player::Two
+            } else {
+                Player::One
+            };
+            noclip().on_death(which)
+        });
+        if skip == Some(true) {
+            return;
+        }
+        let original: DestroyPlayer =
+            unsafe { std::mem::transmute(DESTROY_PLAYER.get().unwrap().trampoline()) };
+        original(this, edx, player, object)
+    }
+
+    extern "fastcall" fn update(this: Ptr, edx: Ptr, dt: f32) {
+        let original: Update = unsafe { std::mem::transmute(UPDATE.get().unwrap().trampoline()) };
+        original(this, edx, dt);
+
+        guard::call("noclip", || {
+            let play_layer = PlayLayer::from_address(this);
+            if play_layer.has_completed_level() {
+                return;
+            }
+            let length = play_layer.level_length();
+            let percent = if length > 0.0 {
+                (play_layer.player1().x() / length * 100.0).clamp(0.0, 100.0)
+            } else {
+                0.0
+            };
+            let mut noclip = noclip();
+            let attempt = match noclip.attempt() {
+                // the game counts respawning at a checkpoint as an attempt
+                Some(last)
+                    if play_layer.is_practice_mode()
+                        && play_layer.checkpoints().next().is_some() =>
+                {
+                    last
+                }
+                _ => play_layer.current_attempt(),
+            };
+            noclip.end_frame(attempt, percent);
+        });
+    }
+}
+
+#[cfg(game_bindings)]
+pub use hook::{install, noclip, uninstall};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_skips_selected_players() {
+        let mut noclip = Noclip::new();
+        assert!(!noclip.on_death(Player::One));
+        noclip.set_enabled(true);
+        assert!(noclip.on_death(Player::One));
+        assert!(!noclip.on_death(Player::Two));
+        noclip.set_players(Players::Both);
+        assert!(noclip.on_death(Player::Two));
+    }
+
+    #[test]
+    fn tracks_deaths() {
+        let mut noclip = Noclip::new();
+        noclip.set_enabled(true);
+        // frames 0..10, dying on 2, 3 and 7
+        for frame in 0..10 {
+            if [2, 3, 7].contains(&frame) {
+                noclip.on_death(Player::One);
+            }
+            noclip.end_frame(1, frame as f32 * 5.0);
+        }
+        let stats = noclip.stats();
+        assert_eq!(stats.frames, 10);
+        assert_eq!(stats.death_frames, 3);
+        assert_eq!(stats.deaths, 2);
+        assert_eq!(stats.first_death_percent, Some(10.0));
+        assert!((stats.accuracy() - 70.0).abs() < 1e-4);
+
+        // restarting resets them
+        noclip.end_frame(2, 0.0);
+        assert_eq!(noclip.stats().frames, 1);
+        assert_eq!(noclip.stats().first_death_percent, None);
+        assert_eq!(NoclipStats::default().accuracy(), 100.0);
+    }
+}
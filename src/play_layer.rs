@@ -27,12 +27,22 @@
 // float PlayLayer.levelLength = PlayLayer + 0x3B4
 // bool PlayLayer.isPractice = PlayLayer + 0x495
 // int PlayLayer.currentAttempt = PlayLayer + 0x4A8
+// CCLayer* PlayLayer.objectLayer = PlayLayer + 0x188
+// StartPosObject* PlayLayer.startPos = PlayLayer + 0x2A0
+// CCArray<CheckpointObject*>* PlayLayer.checkpoints = PlayLayer + 0x2AC
+// CCPoint PlayLayer.playerStartPosition = PlayLayer + 0x2CC
 // PlayerObject* PlayLayer.PlayerObject2 = PlayLayer + 0x228
 // PlayerObject* PlayLayer.PlayerObject1 = PlayLayer + 0x224
 // double PlayLayer.time = PlayLayer + 0x450
+// CCArray<GameObject*>* PlayLayer.objects = PlayLayer + 0x234
+// CCArray<CCArray<GameObject*>*>* PlayLayer.sections = PlayLayer + 0x364
 
 use crate::{
-    get_base, impl_get_set, read_mem, read_ptr, AddressUtils, LevelSettings, PlayerObject, Ptr,
+    checkpoints::CheckpointObject,
+    cocos2d::{CCArray, CCNode, CCPoint},
+    get_base, impl_get_set, read_mem, read_ptr,
+    start_pos::StartPosObject,
+    AddressUtils, GameObject, LevelSettings, PlayerObject, Ptr,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -83,7 +93,7 @@ impl PlayLayer {
     impl_get_set!(level_length, set_level_length, f32, 0x3B4);
     impl_get_set!(is_practice_mode, set_is_practice_mode, bool, 0x495);
     impl_get_set!(is_test_mode, set_is_test_mode, bool, 0x494);
-    impl_get_set!(current_attempt, set_current_attempt, bool, 0x4A8);
+    impl_get_set!(current_attempt, set_current_attempt, i32, 0x4A8);
     impl_get_set!(time, set_time, f64, 0x450);
     impl_get_set!(
         has_level_complete_menu,
@@ -124,6 +134,111 @@ impl PlayLayer {
         LevelSettings::from_address(unsafe { read_ptr(self.address + 0x22C) })
     }
 
+    /// Layer that holds the objects and the players. It is moved by the camera,
+    /// so its children are positioned in level coordinates.
+    #[inline(always)]
+    pub fn object_layer(&self) -> CCNode {
+        CCNode::from_address(unsafe { read_ptr(self.address + 0x188) })
+    }
+
+    /// Start position the level restarts from, null for the start of the level.
+    #[inline(always)]
+    pub fn start_pos(&self) -> StartPosObject {
+        StartPosObject::from_address(unsafe { read_ptr(self.address + 0x2A0) })
+    }
+
+    /// Sets the start position the level restarts from, together with
+    /// [`PlayLayer::set_player_start_position`]. See [`crate::start_pos`].
+    #[inline(always)]
+    pub fn set_start_pos(&self, start_pos: StartPosObject) {
+        unsafe { *read_mem(self.address + 0x2A0) = start_pos.ptr() }
+    }
+
+    /// Where the players are placed when the level restarts.
+    #[inline(always)]
+    pub fn player_start_position(&self) -> CCPoint {
+        unsafe { *read_mem(self.address + 0x2CC) }
+    }
+
+    #[inline(always)]
+    pub fn set_player_start_position(&self, position: impl Into<CCPoint>) {
+        unsafe { *read_mem(self.address + 0x2CC) = position.into() }
+    }
+
+    /// Width of a level section in units. Objects are sorted into sections by their X position.
+    pub const SECTION_WIDTH: f32 = 100.0;
+
+    /// Array of every object in the level.
+    #[inline(always)]
+    pub fn object_array(&self) -> CCArray {
+        CCArray::from_address(unsafe { read_ptr(self.address + 0x234) })
+    }
+
+    /// Array of sections, each one an array of the objects in it.
+    #[inline(always)]
+    pub fn section_array(&self) -> CCArray {
+        CCArray::from_address(unsafe { read_ptr(self.address + 0x364) })
+    }
+
+    /// Iterates over every object in the level.
+    pub fn objects(&self) -> impl Iterator<Item = GameObject> {
+        self.object_array()
+            .iter_as::<GameObject>()
+            .filter(|o| !o.is_null())
+    }
+
+    /// Returns the index of the section that contains `x`.
+    #[inline]
+    pub fn section_for_x(x: f32) -> usize {
+        (x / Self::SECTION_WIDTH).floor().max(0.0) as usize
+    }
+
+    /// Iterates over the objects in the section that contains `x`.
+    pub fn objects_in_section(&self, x: f32) -> impl Iterator<Item = GameObject> {
+        let sections = self.section_array();
+        let section = sections
+            .to_option()
+            .and_then(|s| s.get_as::<CCArray>(Self::section_for_x(x)))
+            .unwrap_or(CCArray::from_address(0));
+        section.iter_as::<GameObject>().filter(|o| !o.is_null())
+    }
+
+    /// Array of the practice mode checkpoints, oldest first.
+    #[inline(always)]
+    pub fn checkpoint_array(&self) -> CCArray {
+        CCArray::from_address(unsafe { read_ptr(self.address + 0x2AC) })
+    }
+
+    /// Iterates over the practice mode checkpoints, oldest first.
+    pub fn checkpoints(&self) -> impl Iterator<Item = CheckpointObject> {
+        self.checkpoint_array()
+            .iter_as::<CheckpointObject>()
+            .filter(|c| !c.is_null())
+    }
+
+    /// Places a practice mode checkpoint where the player is, like the
+    /// checkpoint key. Returns a null checkpoint outside of practice mode or
+    /// while dead.
+    pub fn create_checkpoint(&self) -> CheckpointObject {
+        if !self.is_practice_mode() || self.is_dead_raw() {
+            return CheckpointObject::from_address(0);
+        }
+        self.create_checkpoint_raw()
+    }
+
+    /// Removes every practice mode checkpoint.
+    pub fn clear_checkpoints(&self) {
+        let count = self.checkpoints().count();
+        for _ in 0..count {
+            self.remove_last_checkpoint();
+        }
+    }
+
+    make_func_wrapper!(
+        0x20B050,
+        "Creates a practice checkpoint at the player (PlayLayer::createCheckpoint).",
+        create_checkpoint_raw() -> CheckpointObject
+    );
     make_func_wrapper!(0x2087D0, "Gets the time (in seconds) for a given X position.", time_for_xpos(xpos: f32) -> f32);
     make_func_wrapper!(0x20D0D0, "Toggles practice mode.", toggle_practice_mode(on: bool));
     make_func_wrapper!(
@@ -134,6 +249,8 @@ impl PlayLayer {
     make_func_wrapper!(0x203CD0, "Checks for player collisions.", check_collisions(player: PlayerObject) -> bool);
     make_func_wrapper!(0x20D3C0, "Pauses the game.", pause_game(unk: bool));
     make_func_wrapper!(0x20BF00, "Restarts the level.", reset_level());
+    make_func_wrapper!(0x2029C0, "Advances the level by `dt` seconds.", update(dt: f32));
 }
 
 crate::impl_addr_funcs!(PlayLayer);
+crate::rtti::impl_rtti_class!(PlayLayer, "PlayLayer");
@@ -35,26 +35,7 @@
 // CCMotionStreak* PlayerObject.trail = PlayerObject + 0x510;
 // HardStreak* PlayerObject.wave_trail = PlayerObject + 0x514;
 
-use crate::{impl_get_set, read_mem, Ptr};
-
-/// Player gamemode.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum GameMode {
-    /// Cube gamemode.
-    Cube,
-    /// Ship gamemode.
-    Ship,
-    /// UFO (bird) gamemode.
-    Ufo,
-    /// Ball gamemode.
-    Ball,
-    /// Wave (dart) gamemode.
-    Wave,
-    /// Robot gamemode.
-    Robot,
-    /// Spider gamemode.
-    Spider,
-}
+use crate::{impl_get_set, read_mem, GameMode, Ptr};
 
 /// PlayerObject
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -146,3 +127,4 @@ impl PlayerObject {
 }
 
 crate::impl_addr_funcs!(PlayerObject);
+crate::rtti::impl_rtti_class!(PlayerObject, "PlayerObject");
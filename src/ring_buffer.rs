@@ -0,0 +1,179 @@
+//! Fixed-capacity FIFO ring buffer.
+
+/// A fixed-capacity FIFO of `Copy` values.
+///
+/// Pushing into a full buffer drops the values that don't fit (and counts
+/// them), so a slow consumer never makes the producer allocate or block.
+#[derive(Debug, Clone)]
+pub struct RingBuffer<T> {
+    data: Box<[T]>,
+    /// Index of the oldest value.
+    head: usize,
+    len: usize,
+    dropped: usize,
+}
+
+impl<T: Copy + Default> RingBuffer<T> {
+    /// Creates a ring buffer that holds up to `capacity` values.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            data: vec![T::default(); capacity].into_boxed_slice(),
+            head: 0,
+            len: 0,
+            dropped: 0,
+        }
+    }
+
+    /// Maximum number of values the buffer can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Number of values currently in the buffer.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Number of values that didn't fit since the buffer was created (or cleared).
+    #[inline]
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Appends as many values from `values` as fit, returning how many were written.
+    pub fn push_slice(&mut self, values: &[T]) -> usize {
+        let cap = self.capacity();
+        let count = values.len().min(cap - self.len);
+        self.dropped += values.len() - count;
+
+        let tail = (self.head + self.len) % cap.max(1);
+        let first = count.min(cap - tail);
+        self.data[tail..tail + first].copy_from_slice(&values[..first]);
+        self.data[..count - first].copy_from_slice(&values[first..count]);
+        self.len += count;
+        count
+    }
+
+    /// Appends `value` `count` times, as many times as fits, returning how many were written.
+    pub fn push_repeated(&mut self, value: T, count: usize) -> usize {
+        let cap = self.capacity();
+        let written = count.min(cap - self.len);
+        self.dropped += count - written;
+
+        let tail = (self.head + self.len) % cap.max(1);
+        let first = written.min(cap - tail);
+        self.data[tail..tail + first].fill(value);
+        self.data[..written - first].fill(value);
+        self.len += written;
+        written
+    }
+
+    /// Removes up to `out.len()` of the oldest values into `out`, returning how many were read.
+    pub fn pop_slice(&mut self, out: &mut [T]) -> usize {
+        let cap = self.capacity();
+        let count = out.len().min(self.len);
+
+        let first = count.min(cap - self.head);
+        out[..first].copy_from_slice(&self.data[self.head..self.head + first]);
+        out[first..count].copy_from_slice(&self.data[..count - first]);
+        self.head = (self.head + count) % cap.max(1);
+        self.len -= count;
+        count
+    }
+
+    /// Removes all values and appends them to `out`.
+    pub fn drain_into(&mut self, out: &mut Vec<T>) {
+        let start = out.len();
+        out.resize(start + self.len, T::default());
+        self.pop_slice(&mut out[start..]);
+    }
+
+    /// Removes all values and resets the dropped counter.
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+        self.dropped = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_and_empty() {
+        let mut buffer = RingBuffer::<i32>::new(4);
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.pop_slice(&mut [0; 2]), 0);
+
+        assert_eq!(buffer.push_slice(&[1, 2, 3]), 3);
+        assert_eq!(buffer.push_slice(&[4, 5, 6]), 1);
+        assert!(buffer.is_full());
+        assert_eq!(buffer.dropped(), 2);
+        assert_eq!(buffer.push_slice(&[7]), 0);
+        assert_eq!(buffer.dropped(), 3);
+
+        let mut out = Vec::new();
+        buffer.drain_into(&mut out);
+        assert_eq!(out, [1, 2, 3, 4]);
+        assert!(buffer.is_empty());
+
+        buffer.clear();
+        assert_eq!(buffer.dropped(), 0);
+        let mut empty = RingBuffer::<i32>::new(0);
+        assert_eq!(empty.push_slice(&[1]), 0);
+        assert_eq!(empty.pop_slice(&mut [0]), 0);
+        assert_eq!(empty.dropped(), 1);
+    }
+
+    #[test]
+    fn wraps_around() {
+        let mut buffer = RingBuffer::<i32>::new(5);
+        let mut out = [0; 5];
+        buffer.push_slice(&[1, 2, 3, 4]);
+        assert_eq!(buffer.pop_slice(&mut out[..3]), 3);
+        assert_eq!(out[..3], [1, 2, 3]);
+
+        // the next push wraps past the end of the storage
+        assert_eq!(buffer.push_slice(&[5, 6, 7, 8]), 4);
+        assert!(buffer.is_full());
+        assert_eq!(buffer.pop_slice(&mut out), 5);
+        assert_eq!(out, [4, 5, 6, 7, 8]);
+
+        // so does the next pop
+        buffer.push_slice(&[9, 10, 11]);
+        assert_eq!(buffer.pop_slice(&mut out[..2]), 2);
+        buffer.push_slice(&[12, 13, 14]);
+        let mut rest = Vec::new();
+        buffer.drain_into(&mut rest);
+        assert_eq!(rest, [11, 12, 13, 14]);
+        assert_eq!(buffer.dropped(), 0);
+    }
+
+    #[test]
+    fn push_repeated() {
+        let mut buffer = RingBuffer::<i32>::new(4);
+        buffer.push_slice(&[1, 2, 3]);
+        buffer.pop_slice(&mut [0; 2]);
+        // wraps around, then drops what doesn't fit
+        assert_eq!(buffer.push_repeated(0, 2), 2);
+        assert_eq!(buffer.push_repeated(9, 3), 1);
+        assert_eq!(buffer.dropped(), 2);
+        let mut out = Vec::new();
+        buffer.drain_into(&mut out);
+        assert_eq!(out, [3, 0, 0, 9]);
+        assert_eq!(buffer.push_repeated(0, 0), 0);
+    }
+}
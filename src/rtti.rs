@@ -0,0 +1,443 @@
+//! MSVC run-time type information.
+//!
+//! Every polymorphic object compiled with MSVC starts with a vtable pointer,
+//! and the slot right before the vtable points to a *complete object locator*:
+//!
+//! ```text
+//! object -> vtable -> [-1] CompleteObjectLocator
+//!                             +0x0C TypeDescriptor        -> +0x08 ".?AVPlayLayer@@"
+//!                             +0x10 ClassHierarchyDescriptor
+//!                                     +0x08 number of base classes
+//!                                     +0x0C BaseClassDescriptor*[]
+//!                                             +0x00 TypeDescriptor
+//!                                             +0x08 offset of the base in the object
+//! ```
+//!
+//! The parser reads through a [`Memory`], so it works on the running game as
+//! well as on memory dumps. Only the 32-bit layout (absolute pointers) is
+//! supported, since that's what GD uses.
+
+use std::fmt;
+
+/// Something RTTI can be read from.
+pub trait Memory {
+    /// Reads a little-endian `u32` at `address`.
+    fn read_u32(&self, address: usize) -> Option<u32>;
+
+    /// Reads the byte at `address`.
+    fn read_u8(&self, address: usize) -> Option<u8>;
+}
+
+/// A copy of memory that started at `base`.
+#[derive(Debug, Clone, Copy)]
+pub struct SliceMemory<'a> {
+    pub base: usize,
+    pub data: &'a [u8],
+}
+
+impl<'a> SliceMemory<'a> {
+    pub const fn new(base: usize, data: &'a [u8]) -> Self {
+        Self { base, data }
+    }
+}
+
+impl Memory for SliceMemory<'_> {
+    fn read_u32(&self, address: usize) -> Option<u32> {
+        let start = address.checked_sub(self.base)?;
+        let bytes = self.data.get(start..start.checked_add(4)?)?;
+        Some(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn read_u8(&self, address: usize) -> Option<u8> {
+        self.data.get(address.checked_sub(self.base)?).copied()
+    }
+}
+
+/// Reads the memory of the current process.
+#[cfg(game_bindings)]
+#[derive(Debug, Clone, Copy)]
+pub struct ProcessMemory(());
+
+#[cfg(game_bindings)]
+impl ProcessMemory {
+    /// # Safety
+    ///
+    /// Reads aren't checked, only use this on pointers that are known to
+    /// point to polymorphic objects.
+    pub const unsafe fn new() -> Self {
+        Self(())
+    }
+}
+
+#[cfg(game_bindings)]
+impl Memory for ProcessMemory {
+    fn read_u32(&self, address: usize) -> Option<u32> {
+        if address == 0 {
+            return None;
+        }
+        Some(unsafe { std::ptr::read_unaligned(address as *const u32) })
+    }
+
+    fn read_u8(&self, address: usize) -> Option<u8> {
+        if address == 0 {
+            return None;
+        }
+        Some(unsafe { *(address as *const u8) })
+    }
+}
+
+/// Errors that can happen while parsing RTTI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RttiError {
+    /// Memory at this address couldn't be read.
+    Unreadable(usize),
+    /// The complete object locator has an unexpected signature (1 means a 64-bit locator).
+    BadSignature(u32),
+    /// The type descriptor name isn't a valid class name.
+    BadName(String),
+}
+
+impl fmt::Display for RttiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unreadable(address) => write!(f, "can't read memory at {address:#x}"),
+            Self::BadSignature(signature) => {
+                write!(f, "unsupported object locator signature {signature}")
+            }
+            Self::BadName(name) => write!(f, "invalid type name {name:?}"),
+        }
+    }
+}
+
+impl std::error::Error for RttiError {}
+
+/// A base class of a [`TypeInfo`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaseClass {
+    /// Readable class name, e.g. `cocos2d::CCLayer`.
+    pub name: String,
+    /// Offset of the base class inside the complete object.
+    pub offset: i32,
+    /// Number of bases this base itself has.
+    pub contained_bases: u32,
+}
+
+/// Type information of a polymorphic object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeInfo {
+    /// Readable class name, e.g. `PlayLayer`.
+    pub name: String,
+    /// Decorated name, e.g. `.?AVPlayLayer@@`.
+    pub raw_name: String,
+    /// Offset of the vtable the object was read through inside the complete
+    /// object (non-zero when the pointer is to a secondary base).
+    pub offset: u32,
+    /// Every class in the hierarchy, starting with the class itself.
+    pub bases: Vec<BaseClass>,
+}
+
+impl TypeInfo {
+    /// Returns whether the object is a `name` or derives from it.
+    pub fn is(&self, name: &str) -> bool {
+        self.base(name).is_some()
+    }
+
+    /// Returns the base class (or the class itself) called `name`.
+    pub fn base(&self, name: &str) -> Option<&BaseClass> {
+        self.bases.iter().find(|b| b.name == name)
+    }
+}
+
+/// The longest type name that is read before giving up.
+const MAX_NAME_LEN: usize = 1024;
+
+fn read_ptr<M: Memory + ?Sized>(memory: &M, address: usize) -> Result<usize, RttiError> {
+    memory
+        .read_u32(address)
+        .map(|v| v as usize)
+        .ok_or(RttiError::Unreadable(address))
+}
+
+fn read_name<M: Memory + ?Sized>(memory: &M, address: usize) -> Result<String, RttiError> {
+    let mut bytes = Vec::new();
+    loop {
+        let byte = memory
+            .read_u8(address + bytes.len())
+            .ok_or(RttiError::Unreadable(address + bytes.len()))?;
+        if byte == 0 {
+            break;
+        }
+        bytes.push(byte);
+        if bytes.len() > MAX_NAME_LEN {
+            return Err(RttiError::BadName(String::from_utf8_lossy(&bytes).into()));
+        }
+    }
+    String::from_utf8(bytes).map_err(|e| RttiError::BadName(e.to_string()))
+}
+
+/// Reads the decorated name of a type descriptor, returning it and its readable form.
+fn read_type_descriptor<M: Memory + ?Sized>(
+    memory: &M,
+    descriptor: usize,
+) -> Result<(String, String), RttiError> {
+    let raw_name = read_name(memory, descriptor + 8)?;
+    match demangle_type_name(&raw_name) {
+        Some(name) => Ok((raw_name, name)),
+        None => Err(RttiError::BadName(raw_name)),
+    }
+}
+
+/// Reads the type information of the object at `address`.
+pub fn type_info<M: Memory + ?Sized>(memory: &M, address: usize) -> Result<TypeInfo, RttiError> {
+    let vtable = read_ptr(memory, address)?;
+    let locator = read_ptr(memory, vtable.wrapping_sub(4))?;
+
+    let signature = read_ptr(memory, locator)? as u32;
+    if signature != 0 {
+        return Err(RttiError::BadSignature(signature));
+    }
+    let offset = read_ptr(memory, locator + 4)? as u32;
+    let (raw_name, name) = read_type_descriptor(memory, read_ptr(memory, locator + 12)?)?;
+
+    let hierarchy = read_ptr(memory, locator + 16)?;
+    let count = read_ptr(memory, hierarchy + 8)?;
+    let array = read_ptr(memory, hierarchy + 12)?;
+    let mut bases = Vec::with_capacity(count.min(64));
+    for i in 0..count {
+        let descriptor = read_ptr(memory, array + i * 4)?;
+        let (_, name) = read_type_descriptor(memory, read_ptr(memory, descriptor)?)?;
+        bases.push(BaseClass {
+            name,
+            contained_bases: read_ptr(memory, descriptor + 4)? as u32,
+            offset: read_ptr(memory, descriptor + 8)? as u32 as i32,
+        });
+    }
+
+    Ok(TypeInfo {
+        name,
+        raw_name,
+        offset,
+        bases,
+    })
+}
+
+/// Turns an MSVC type descriptor name (e.g. `.?AVCCNode@cocos2d@@`) into
+/// a readable class name (`cocos2d::CCNode`).
+///
+/// Returns `None` for names that don't describe a class or struct.
+pub fn demangle_type_name(name: &str) -> Option<String> {
+    let name = name
+        .strip_prefix(".?AV")
+        .or_else(|| name.strip_prefix(".?AU"))?
+        .strip_suffix("@@")?;
+    // nested names are stored innermost first
+    let parts: Vec<&str> = name.split('@').collect();
+    if parts.iter().any(|p| p.is_empty()) {
+        return None;
+    }
+    Some(parts.into_iter().rev().collect::<Vec<_>>().join("::"))
+}
+
+/// Returns the class name of the polymorphic object at `address`.
+///
+/// # Safety
+///
+/// `address` must point to an object with a vtable.
+#[cfg(game_bindings)]
+pub unsafe fn class_name(address: crate::Ptr) -> Option<String> {
+    type_info(&ProcessMemory::new(), address)
+        .ok()
+        .map(|t| t.name)
+}
+
+/// A game class that objects can be downcast to with [`downcast`].
+#[cfg(game_bindings)]
+pub trait RttiClass: Sized {
+    /// Class name as returned by [`demangle_type_name`].
+    const CLASS_NAME: &'static str;
+
+    fn from_address(address: crate::Ptr) -> Self;
+}
+
+/// Casts the polymorphic object at `address` to `T`, if it is a `T` or derives from it.
+///
+/// # Safety
+///
+/// `address` must point to an object with a vtable.
+#[cfg(game_bindings)]
+pub unsafe fn downcast<T: RttiClass>(address: crate::Ptr) -> Option<T> {
+    if address == 0 {
+        return None;
+    }
+    let info = type_info(&ProcessMemory::new(), address).ok()?;
+    let base = info.base(T::CLASS_NAME)?;
+    let complete = address.wrapping_sub(info.offset as usize);
+    Some(T::from_address(
+        complete.wrapping_add_signed(base.offset as isize),
+    ))
+}
+
+/// Implements [`RttiClass`] for a handle type.
+#[cfg(game_bindings)]
+macro_rules! impl_rtti_class {
+    ($t:ty, $name:literal) => {
+        impl $crate::rtti::RttiClass for $t {
+            const CLASS_NAME: &'static str = $name;
+
+            #[inline(always)]
+            fn from_address(address: $crate::Ptr) -> Self {
+                Self { address }
+            }
+        }
+    };
+}
+
+#[cfg(game_bindings)]
+pub(crate) use impl_rtti_class;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds the RTTI of `class Derived : public Base, public Other` at 0x1000.
+    struct Builder {
+        data: Vec<u8>,
+    }
+
+    const BASE: usize = 0x1000;
+
+    impl Builder {
+        fn new() -> Self {
+            Self {
+                data: vec![0; 0x400],
+            }
+        }
+
+        fn put(&mut self, address: usize, value: u32) {
+            let at = address - BASE;
+            self.data[at..at + 4].copy_from_slice(&value.to_le_bytes());
+        }
+
+        fn put_str(&mut self, address: usize, s: &str) {
+            let at = address - BASE;
+            self.data[at..at + s.len()].copy_from_slice(s.as_bytes());
+            self.data[at + s.len()] = 0;
+        }
+
+        fn build() -> Self {
+            let mut b = Self::new();
+            // type descriptors
+            b.put_str(0x1108, ".?AVPlayLayer@@");
+            b.put_str(0x1148, ".?AVCCLayer@cocos2d@@");
+            b.put_str(0x1188, ".?AVCCNode@cocos2d@@");
+            b.put_str(0x11C8, ".?AUCCKeyboardDelegate@cocos2d@@");
+            // base class descriptors: (type descriptor, contained bases, mdisp)
+            for (at, td, contained, mdisp) in [
+                (0x1200, 0x1100, 3, 0),
+                (0x1220, 0x1140, 1, 0),
+                (0x1240, 0x1180, 0, 0),
+                (0x1260, 0x11C0, 0, 0x40),
+            ] {
+                b.put(at, td);
+                b.put(at + 4, contained);
+                b.put(at + 8, mdisp);
+                b.put(at + 12, u32::MAX);
+            }
+            // base class array
+            for (i, bcd) in [0x1200, 0x1220, 0x1240, 0x1260].into_iter().enumerate() {
+                b.put(0x1280 + i * 4, bcd);
+            }
+            // class hierarchy descriptor
+            b.put(0x12A0 + 8, 4);
+            b.put(0x12A0 + 12, 0x1280);
+            // complete object locators, for the main vtable and the secondary one
+            for (col, offset) in [(0x12C0, 0), (0x12E0, 0x40)] {
+                b.put(col + 4, offset);
+                b.put(col + 12, 0x1100);
+                b.put(col + 16, 0x12A0);
+            }
+            // vtables (locator pointer right before them)
+            b.put(0x1300, 0x12C0);
+            b.put(0x1340, 0x12E0);
+            // objects: the complete object and a pointer to its secondary base
+            b.put(0x1000, 0x1304);
+            b.put(0x1000 + 0x40, 0x1344);
+            b
+        }
+
+        fn memory(&self) -> SliceMemory<'_> {
+            SliceMemory::new(BASE, &self.data)
+        }
+    }
+
+    #[test]
+    fn demangle() {
+        assert_eq!(demangle_type_name(".?AVPlayLayer@@").unwrap(), "PlayLayer");
+        assert_eq!(
+            demangle_type_name(".?AVCCNode@cocos2d@@").unwrap(),
+            "cocos2d::CCNode"
+        );
+        assert_eq!(
+            demangle_type_name(".?AUStruct@inner@outer@@").unwrap(),
+            "outer::inner::Struct"
+        );
+        assert_eq!(demangle_type_name(".?AW4Enum@@"), None);
+        assert_eq!(demangle_type_name("PlayLayer"), None);
+    }
+
+    #[test]
+    fn parse_complete_object() {
+        let b = Builder::build();
+        let info = type_info(&b.memory(), 0x1000).unwrap();
+        assert_eq!(info.name, "PlayLayer");
+        assert_eq!(info.raw_name, ".?AVPlayLayer@@");
+        assert_eq!(info.offset, 0);
+        let names: Vec<&str> = info.bases.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "PlayLayer",
+                "cocos2d::CCLayer",
+                "cocos2d::CCNode",
+                "cocos2d::CCKeyboardDelegate"
+            ]
+        );
+        assert!(info.is("cocos2d::CCNode"));
+        assert!(!info.is("PauseLayer"));
+        assert_eq!(
+            info.base("cocos2d::CCKeyboardDelegate").unwrap().offset,
+            0x40
+        );
+        assert_eq!(info.base("PlayLayer").unwrap().contained_bases, 3);
+    }
+
+    #[test]
+    fn parse_secondary_base() {
+        let b = Builder::build();
+        let info = type_info(&b.memory(), 0x1000 + 0x40).unwrap();
+        assert_eq!(info.name, "PlayLayer");
+        assert_eq!(info.offset, 0x40);
+    }
+
+    #[test]
+    fn errors() {
+        let mut b = Builder::build();
+        assert_eq!(
+            type_info(&b.memory(), 0x2000),
+            Err(RttiError::Unreadable(0x2000))
+        );
+
+        b.put(0x12C0, 1);
+        assert_eq!(
+            type_info(&b.memory(), 0x1000),
+            Err(RttiError::BadSignature(1))
+        );
+
+        let mut b = Builder::build();
+        b.put_str(0x1108, "garbage");
+        assert_eq!(
+            type_info(&b.memory(), 0x1000),
+            Err(RttiError::BadName("garbage".into()))
+        );
+    }
+}
@@ -0,0 +1,212 @@
+#[cfg(game_bindings)]
+use crate::cocos2d::CCDirector;
+
+/// Commonly used speedhack values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpeedPreset {
+    /// 0.25x
+    Quarter,
+    /// 0.5x
+    Half,
+    /// 0.75x
+    ThreeQuarters,
+    /// 1.0x
+    Normal,
+    /// 1.5x
+    OneAndHalf,
+    /// 2.0x
+    Double,
+}
+
+impl SpeedPreset {
+    /// All presets, from slowest to fastest.
+    pub const ALL: [SpeedPreset; 6] = [
+        Self::Quarter,
+        Self::Half,
+        Self::ThreeQuarters,
+        Self::Normal,
+        Self::OneAndHalf,
+        Self::Double,
+    ];
+
+    /// Returns the timescale of this preset.
+    #[inline]
+    pub const fn speed(self) -> f32 {
+        match self {
+            Self::Quarter => 0.25,
+            Self::Half => 0.5,
+            Self::ThreeQuarters => 0.75,
+            Self::Normal => 1.0,
+            Self::OneAndHalf => 1.5,
+            Self::Double => 2.0,
+        }
+    }
+}
+
+impl From<SpeedPreset> for f32 {
+    fn from(value: SpeedPreset) -> f32 {
+        value.speed()
+    }
+}
+
+/// Keeps the game speed and the music in sync.
+///
+/// The game speed is controlled through the [`CCScheduler`](crate::cocos2d::CCScheduler)
+/// timescale. With the `fmod` feature, the frequency of the currently playing
+/// music channel is scaled by the same amount, so the song doesn't drift away
+/// from the level.
+///
+/// GD recreates the music channel on every attempt, so [`Speedhack::apply`]
+/// should be called every frame (e.g. from a `PlayLayer::update` hook), and
+/// [`Speedhack::on_level_exit`] when leaving a level.
+#[cfg(game_bindings)]
+#[derive(Debug, Clone)]
+pub struct Speedhack {
+    speed: f32,
+    sync_audio: bool,
+    /// The music channel we last touched, and its original frequency.
+    #[cfg(feature = "fmod")]
+    channel: Option<(*mut crate::fmod::FMOD_CHANNEL, f32)>,
+}
+
+#[cfg(game_bindings)]
+impl Default for Speedhack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(game_bindings)]
+impl Speedhack {
+    pub const fn new() -> Self {
+        Self {
+            speed: 1.0,
+            sync_audio: true,
+            #[cfg(feature = "fmod")]
+            channel: None,
+        }
+    }
+
+    /// Returns the configured speed.
+    #[inline]
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Sets the speed and applies it immediately. Non-positive values are ignored.
+    pub fn set_speed<S: Into<f32>>(&mut self, speed: S) {
+        let speed = speed.into();
+        if speed > 0.0 && speed.is_finite() {
+            self.speed = speed;
+            self.apply();
+        }
+    }
+
+    /// Returns whether the music is kept in sync with the game speed.
+    #[inline]
+    pub fn sync_audio(&self) -> bool {
+        self.sync_audio
+    }
+
+    /// Enables or disables scaling the music with the game speed.
+    pub fn set_sync_audio(&mut self, sync_audio: bool) {
+        self.sync_audio = sync_audio;
+        if sync_audio {
+            self.apply_audio();
+        } else {
+            self.restore_audio();
+        }
+    }
+
+    /// Applies the configured speed to the scheduler and the music channel.
+    pub fn apply(&mut self) {
+        let scheduler = CCDirector::shared().scheduler();
+        if scheduler.get_timescale() != self.speed {
+            scheduler.set_timescale(self.speed);
+        }
+        if self.sync_audio {
+            self.apply_audio();
+        }
+    }
+
+    /// Seeks the music to match the level time, e.g. after respawning with practice music.
+    ///
+    /// `offset` is the song offset of the level in seconds.
+    #[cfg(feature = "fmod")]
+    pub fn sync_music_position(&self, play_layer: crate::PlayLayer, offset: f64) {
+        use crate::AddressUtils;
+
+        if play_layer.is_null() {
+            return;
+        }
+        let engine = crate::FMODAudioEngine::shared();
+        if let Some(channel) = engine.music_channel() {
+            let position = ((play_layer.time() + offset).max(0.0) * 1000.0) as u32;
+            let _ = channel.set_position(position);
+        }
+    }
+
+    /// Resets the game speed and the music to normal, keeping the configured speed
+    /// so it can be reapplied with [`Speedhack::apply`] on the next level.
+    pub fn on_level_exit(&mut self) {
+        CCDirector::shared().scheduler().set_timescale(1.0);
+        self.restore_audio();
+    }
+
+    /// Resets everything to normal speed.
+    pub fn reset(&mut self) {
+        self.speed = 1.0;
+        self.on_level_exit();
+    }
+
+    #[cfg(feature = "fmod")]
+    fn apply_audio(&mut self) {
+        let engine = crate::FMODAudioEngine::shared();
+        let Some(channel) = engine.music_channel() else {
+            self.channel = None;
+            return;
+        };
+
+        // remember the frequency of a new channel before we change it
+        let base = match self.channel {
+            Some((ch, base)) if ch == channel.as_raw() => base,
+            _ => {
+                let Ok(base) = channel.frequency() else {
+                    return;
+                };
+                self.channel = Some((channel.as_raw(), base));
+                base
+            }
+        };
+        let _ = channel.set_frequency(base * self.speed);
+    }
+
+    #[cfg(not(feature = "fmod"))]
+    fn apply_audio(&mut self) {}
+
+    #[cfg(feature = "fmod")]
+    fn restore_audio(&mut self) {
+        if let Some((raw, base)) = self.channel.take() {
+            // only restore if the game is still playing the same channel
+            let engine = crate::FMODAudioEngine::shared();
+            if let Some(channel) = engine.music_channel().filter(|c| c.as_raw() == raw) {
+                let _ = channel.set_frequency(base);
+            }
+        }
+    }
+
+    #[cfg(not(feature = "fmod"))]
+    fn restore_audio(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets() {
+        let speeds: Vec<f32> = SpeedPreset::ALL.into_iter().map(f32::from).collect();
+        assert_eq!(speeds, [0.25, 0.5, 0.75, 1.0, 1.5, 2.0]);
+        assert_eq!(SpeedPreset::Normal.speed(), 1.0);
+    }
+}
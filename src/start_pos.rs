@@ -0,0 +1,429 @@
+//! Start positions: places in a level the player can start from instead of
+//! the beginning, with their own gamemode, speed...
+//!
+//! [`StartPosCycle`] keeps track of which one is selected. In the game,
+//! `StartPosManager` lists the start positions of the current level (plus the
+//! ones created with it), switches between them and restarts the level:
+//!
+//! ```ignore
+//! use geometrydash::{start_pos::StartPosManager, AddressUtils, GameManager};
+//!
+//! let play_layer = GameManager::shared().play_layer().to_option().unwrap();
+//! let mut manager = StartPosManager::load(play_layer);
+//! for (i, start) in manager.positions().iter().enumerate() {
+//!     println!("{i}: {:?} {:?}", start.position(), start.settings());
+//! }
+//! manager.next(play_layer);
+//! ```
+
+use crate::GameMode;
+
+/// Player speed, the game's `Speed` enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Speed {
+    /// 0.5x
+    Slow,
+    /// 1x
+    #[default]
+    Normal,
+    /// 2x
+    Fast,
+    /// 3x
+    Faster,
+    /// 4x
+    Fastest,
+}
+
+impl Speed {
+    pub const fn from_raw(raw: i32) -> Option<Self> {
+        Some(match raw {
+            0 => Self::Normal,
+            1 => Self::Slow,
+            2 => Self::Fast,
+            3 => Self::Faster,
+            4 => Self::Fastest,
+            _ => return None,
+        })
+    }
+
+    pub const fn raw(self) -> i32 {
+        match self {
+            Self::Normal => 0,
+            Self::Slow => 1,
+            Self::Fast => 2,
+            Self::Faster => 3,
+            Self::Fastest => 4,
+        }
+    }
+}
+
+/// The state a start position puts the player in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StartSettings {
+    pub mode: GameMode,
+    pub speed: Speed,
+    pub mini: bool,
+    pub dual: bool,
+    pub mirror: bool,
+    /// Upside down.
+    pub flip_gravity: bool,
+}
+
+impl Default for StartSettings {
+    fn default() -> Self {
+        Self {
+            mode: GameMode::Cube,
+            speed: Speed::Normal,
+            mini: false,
+            dual: false,
+            mirror: false,
+            flip_gravity: false,
+        }
+    }
+}
+
+/// Which of `len` start positions is selected, `None` being the start of
+/// the level. Cycles through them in order, wrapping around through `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StartPosCycle {
+    len: usize,
+    current: Option<usize>,
+}
+
+impl StartPosCycle {
+    pub const fn new(len: usize) -> Self {
+        Self { len, current: None }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Changes the number of start positions, deselecting if the selected one is gone.
+    pub fn set_len(&mut self, len: usize) {
+        self.len = len;
+        self.current = self.current.filter(|&i| i < len);
+    }
+
+    #[inline]
+    pub fn current(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Selects a start position, ignoring indices out of range.
+    pub fn select(&mut self, index: Option<usize>) -> Option<usize> {
+        if index.is_none_or(|i| i < self.len) {
+            self.current = index;
+        }
+        self.current
+    }
+
+    /// Selects the next start position, the start of the level after the last one.
+    pub fn select_next(&mut self) -> Option<usize> {
+        self.current = match self.current {
+            None if self.len > 0 => Some(0),
+            Some(i) if i + 1 < self.len => Some(i + 1),
+            _ => None,
+        };
+        self.current
+    }
+
+    /// Selects the previous start position, the last one before the start of the level.
+    pub fn select_prev(&mut self) -> Option<usize> {
+        self.current = match self.current {
+            None => self.len.checked_sub(1),
+            Some(i) => i.checked_sub(1),
+        };
+        self.current
+    }
+}
+
+#[cfg(game_bindings)]
+mod game {
+    use super::*;
+    use crate::{
+        cocos2d::CCPoint, impl_addr_funcs, read_ptr, AddressUtils, GameObject, LevelSettings,
+        PlayLayer, Ptr,
+    };
+
+    // LevelSettingsObject* StartPosObject.levelSettings = StartPosObject + 0x470
+
+    /// A start position placed in the level (object ID 31).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(transparent)]
+    pub struct StartPosObject {
+        address: Ptr,
+    }
+
+    impl StartPosObject {
+        /// Object ID of start positions.
+        pub const OBJECT_ID: i32 = 31;
+
+        #[inline(always)]
+        pub fn from_address(address: Ptr) -> Self {
+            Self { address }
+        }
+
+        /// Returns the object as a start position, if it is one.
+        pub fn from_object(object: GameObject) -> Option<Self> {
+            (!object.is_null() && object.object_id() == Self::OBJECT_ID)
+                .then(|| Self::from_address(object.ptr()))
+        }
+
+        #[inline(always)]
+        pub fn as_object(&self) -> GameObject {
+            GameObject::from_address(self.address)
+        }
+
+        /// Position of the object in the level.
+        pub fn position(&self) -> CCPoint {
+            let object = self.as_object();
+            CCPoint::new(object.x(), object.y())
+        }
+
+        /// The settings the player gets when starting here.
+        #[inline(always)]
+        pub fn level_settings(&self) -> LevelSettings {
+            LevelSettings::from_address(unsafe { read_ptr(self.address + 0x470) })
+        }
+
+        /// Reads the settings, defaults if the object has none.
+        pub fn settings(&self) -> StartSettings {
+            read_settings(self.level_settings())
+        }
+
+        /// Changes the settings, they are applied the next time the level restarts from here.
+        pub fn set_settings(&self, start: StartSettings) {
+            write_settings(self.level_settings(), start);
+        }
+    }
+
+    fn read_settings(settings: LevelSettings) -> StartSettings {
+        let Some(settings) = settings.to_option() else {
+            return StartSettings::default();
+        };
+        StartSettings {
+            mode: settings.start_mode(),
+            speed: Speed::from_raw(settings.start_speed_raw()).unwrap_or_default(),
+            mini: settings.start_mini(),
+            dual: settings.start_dual(),
+            mirror: settings.start_mirrored(),
+            flip_gravity: settings.start_flipped(),
+        }
+    }
+
+    fn write_settings(settings: LevelSettings, start: StartSettings) {
+        let Some(settings) = settings.to_option() else {
+            return;
+        };
+        settings.set_start_mode(start.mode);
+        settings.set_start_speed_raw(start.speed.raw());
+        settings.set_start_mini(start.mini);
+        settings.set_start_dual(start.dual);
+        settings.set_start_mirrored(start.mirror);
+        settings.set_start_flipped(start.flip_gravity);
+    }
+
+    impl_addr_funcs!(StartPosObject);
+    crate::rtti::impl_rtti_class!(StartPosObject, "StartPosObject");
+
+    /// A place the level can be started from.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum StartPos {
+        /// A start position object of the level.
+        Object(StartPosObject),
+        /// A position created with [`StartPosManager::create`].
+        ///
+        /// No start position object is added to the level: starting here moves
+        /// the player's start position and writes `settings` to the settings
+        /// of the level, which the game then starts the player with.
+        Custom {
+            position: CCPoint,
+            settings: StartSettings,
+        },
+    }
+
+    impl StartPos {
+        pub fn position(&self) -> CCPoint {
+            match self {
+                Self::Object(object) => object.position(),
+                Self::Custom { position, .. } => *position,
+            }
+        }
+
+        /// Settings the player gets when starting here.
+        pub fn settings(&self) -> StartSettings {
+            match self {
+                Self::Object(object) => object.settings(),
+                Self::Custom { settings, .. } => *settings,
+            }
+        }
+    }
+
+    /// Lists the start positions of a level and switches between them.
+    ///
+    /// Selecting a [`StartPos::Custom`] overwrites the settings of the level in
+    /// place. Selecting another start position puts them back, otherwise call
+    /// [`StartPosManager::restore`] before leaving the level.
+    #[derive(Debug, Clone)]
+    pub struct StartPosManager {
+        positions: Vec<StartPos>,
+        cycle: StartPosCycle,
+        /// Where the player starts without a start position.
+        level_start: CCPoint,
+        /// Settings of the level, restored when leaving a custom start position.
+        level_settings: StartSettings,
+    }
+
+    impl StartPosManager {
+        /// Where players start in levels without start positions.
+        pub const LEVEL_START: CCPoint = CCPoint::new(0.0, 105.0);
+
+        /// Collects the start positions of the level, ordered by X position,
+        /// and selects the one the level currently starts from.
+        pub fn load(play_layer: PlayLayer) -> Self {
+            let mut positions: Vec<StartPos> = play_layer
+                .objects()
+                .filter_map(StartPosObject::from_object)
+                .map(StartPos::Object)
+                .collect();
+            positions.sort_by(|a, b| a.position().x.total_cmp(&b.position().x));
+
+            let mut cycle = StartPosCycle::new(positions.len());
+            let active = play_layer.start_pos().ptr();
+            let current = positions
+                .iter()
+                .position(|p| matches!(p, StartPos::Object(o) if o.ptr() == active));
+            cycle.select(current);
+            let level_start = if active == 0 {
+                play_layer.player_start_position()
+            } else {
+                Self::LEVEL_START
+            };
+            Self {
+                positions,
+                cycle,
+                level_start,
+                level_settings: read_settings(play_layer.level_settings()),
+            }
+        }
+
+        #[inline]
+        pub fn positions(&self) -> &[StartPos] {
+            &self.positions
+        }
+
+        /// Index of the selected start position, `None` for the start of the level.
+        #[inline]
+        pub fn current(&self) -> Option<usize> {
+            self.cycle.current()
+        }
+
+        /// Starts the level from a start position (or from the beginning for
+        /// `None`) and restarts it. Out of range indices are ignored.
+        pub fn select(&mut self, play_layer: PlayLayer, index: Option<usize>) {
+            let index = self.cycle.select(index);
+            self.apply(play_layer, index);
+        }
+
+        /// Switches to the next start position and restarts the level.
+        pub fn next(&mut self, play_layer: PlayLayer) {
+            let index = self.cycle.select_next();
+            self.apply(play_layer, index);
+        }
+
+        /// Switches to the previous start position and restarts the level.
+        pub fn prev(&mut self, play_layer: PlayLayer) {
+            let index = self.cycle.select_prev();
+            self.apply(play_layer, index);
+        }
+
+        /// Adds a start position at `position`, starting the player with
+        /// `settings`, and returns its index. It isn't selected.
+        pub fn create(&mut self, position: impl Into<CCPoint>, settings: StartSettings) -> usize {
+            self.positions.push(StartPos::Custom {
+                position: position.into(),
+                settings,
+            });
+            self.cycle.set_len(self.positions.len());
+            self.positions.len() - 1
+        }
+
+        /// Writes back the settings the level had when the manager was loaded,
+        /// undoing what selecting a custom start position changed.
+        pub fn restore(&self, play_layer: PlayLayer) {
+            write_settings(play_layer.level_settings(), self.level_settings);
+        }
+
+        /// Removes a start position created with [`StartPosManager::create`].
+        pub fn remove(&mut self, index: usize) -> Option<StartPos> {
+            if !matches!(self.positions.get(index), Some(StartPos::Custom { .. })) {
+                return None;
+            }
+            let current = self.cycle.current();
+            let removed = self.positions.remove(index);
+            self.cycle.set_len(self.positions.len());
+            self.cycle.select(match current {
+                Some(i) if i == index => None,
+                Some(i) if i > index => Some(i - 1),
+                other => other,
+            });
+            Some(removed)
+        }
+
+        fn apply(&self, play_layer: PlayLayer, index: Option<usize>) {
+            let none = StartPosObject::from_address(0);
+            let (object, position, settings) = match index.and_then(|i| self.positions.get(i)) {
+                Some(StartPos::Object(object)) => (*object, object.position(), self.level_settings),
+                Some(StartPos::Custom { position, settings }) => (none, *position, *settings),
+                None => (none, self.level_start, self.level_settings),
+            };
+            write_settings(play_layer.level_settings(), settings);
+            play_layer.set_start_pos(object);
+            play_layer.set_player_start_position(position);
+            play_layer.reset_level();
+        }
+    }
+}
+
+#[cfg(game_bindings)]
+pub use game::{StartPos, StartPosManager, StartPosObject};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycles_through_level_start() {
+        let mut cycle = StartPosCycle::new(3);
+        assert_eq!(cycle.current(), None);
+        let forward: Vec<_> = (0..4).map(|_| cycle.select_next()).collect();
+        assert_eq!(forward, [Some(0), Some(1), Some(2), None]);
+        let back: Vec<_> = (0..4).map(|_| cycle.select_prev()).collect();
+        assert_eq!(back, [Some(2), Some(1), Some(0), None]);
+
+        assert_eq!(cycle.select(Some(5)), None);
+        assert_eq!(cycle.select(Some(2)), Some(2));
+        cycle.set_len(2);
+        assert_eq!(cycle.current(), None);
+
+        let mut empty = StartPosCycle::new(0);
+        assert_eq!(empty.select_next(), None);
+        assert_eq!(empty.select_prev(), None);
+    }
+
+    #[test]
+    fn raw_values() {
+        for raw in 0..5 {
+            assert_eq!(Speed::from_raw(raw).unwrap().raw(), raw);
+        }
+        assert_eq!(Speed::from_raw(1), Some(Speed::Slow));
+        assert_eq!(Speed::from_raw(5), None);
+    }
+}
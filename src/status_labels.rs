@@ -0,0 +1,317 @@
+//! HUD text stacked in the corners of the screen.
+//!
+//! [`StatusBoard`] keeps the labels (text, corner, color...) and lays them out,
+//! it doesn't touch the game. [`StatusLabels`] shows a board on the running
+//! scene with `CCLabelBMFont`s and moves them to the new scene when it changes,
+//! so labels stay on screen from the menu to the level and back.
+
+/// A corner of the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Corner {
+    /// Returns the anchor point that puts a label's corner at its position.
+    pub fn anchor(self) -> (f32, f32) {
+        match self {
+            Corner::TopLeft => (0.0, 1.0),
+            Corner::TopRight => (1.0, 1.0),
+            Corner::BottomLeft => (0.0, 0.0),
+            Corner::BottomRight => (1.0, 0.0),
+        }
+    }
+
+    #[inline]
+    pub fn is_top(self) -> bool {
+        matches!(self, Corner::TopLeft | Corner::TopRight)
+    }
+
+    #[inline]
+    pub fn is_left(self) -> bool {
+        matches!(self, Corner::TopLeft | Corner::BottomLeft)
+    }
+}
+
+/// Identifies a label of a [`StatusBoard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StatusId(u32);
+
+/// A label of a [`StatusBoard`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusEntry {
+    pub id: StatusId,
+    pub corner: Corner,
+    pub text: String,
+    pub visible: bool,
+    pub color: (u8, u8, u8),
+    pub opacity: u8,
+}
+
+/// Where a label goes on the screen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LabelPlacement {
+    pub id: StatusId,
+    pub position: (f32, f32),
+    pub anchor: (f32, f32),
+}
+
+/// The labels shown in the corners of the screen, in the order they were added.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusBoard {
+    entries: Vec<StatusEntry>,
+    next_id: u32,
+    /// Distance between the labels and the edges of the screen.
+    pub margin: f32,
+    /// Height of a line, labels of the same corner are this far apart.
+    pub line_height: f32,
+    /// Scale of the labels.
+    pub scale: f32,
+    pub font: String,
+}
+
+impl Default for StatusBoard {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            next_id: 0,
+            margin: 5.0,
+            line_height: 16.0,
+            scale: 0.5,
+            font: "bigFont.fnt".to_string(),
+        }
+    }
+}
+
+impl StatusBoard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a label below (or above, for bottom corners) the others of its corner.
+    pub fn add<S: Into<String>>(&mut self, corner: Corner, text: S) -> StatusId {
+        let id = StatusId(self.next_id);
+        self.next_id += 1;
+        self.entries.push(StatusEntry {
+            id,
+            corner,
+            text: text.into(),
+            visible: true,
+            color: (255, 255, 255),
+            opacity: 255,
+        });
+        id
+    }
+
+    /// Removes a label, returns whether it existed.
+    pub fn remove(&mut self, id: StatusId) -> bool {
+        let len = self.entries.len();
+        self.entries.retain(|e| e.id != id);
+        self.entries.len() != len
+    }
+
+    pub fn get(&self, id: StatusId) -> Option<&StatusEntry> {
+        self.entries.iter().find(|e| e.id == id)
+    }
+
+    pub fn get_mut(&mut self, id: StatusId) -> Option<&mut StatusEntry> {
+        self.entries.iter_mut().find(|e| e.id == id)
+    }
+
+    /// Changes the text of a label, does nothing if it doesn't exist.
+    pub fn set_text<S: Into<String>>(&mut self, id: StatusId, text: S) {
+        if let Some(entry) = self.get_mut(id) {
+            entry.text = text.into();
+        }
+    }
+
+    /// Shows or hides a label. Hidden labels don't take space in their corner.
+    pub fn set_visible(&mut self, id: StatusId, visible: bool) {
+        if let Some(entry) = self.get_mut(id) {
+            entry.visible = visible;
+        }
+    }
+
+    pub fn entries(&self) -> &[StatusEntry] {
+        &self.entries
+    }
+
+    /// Computes where the visible labels go on a screen of the given size.
+    pub fn layout(&self, win_size: (f32, f32)) -> Vec<LabelPlacement> {
+        let mut lines = [0usize; 4];
+        self.entries
+            .iter()
+            .filter(|e| e.visible)
+            .map(|e| {
+                let line = &mut lines[e.corner as usize];
+                let offset = self.margin + *line as f32 * self.line_height;
+                *line += 1;
+                let x = if e.corner.is_left() {
+                    self.margin
+                } else {
+                    win_size.0 - self.margin
+                };
+                let y = if e.corner.is_top() {
+                    win_size.1 - offset
+                } else {
+                    offset
+                };
+                LabelPlacement {
+                    id: e.id,
+                    position: (x, y),
+                    anchor: e.corner.anchor(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Shows a [`StatusBoard`] on the running scene.
+///
+/// ```no_run
+/// use geometrydash::status_labels::{Corner, StatusLabels};
+///
+/// let mut labels = StatusLabels::new();
+/// let fps = labels.board.add(Corner::TopLeft, "FPS: 0");
+/// // every frame:
+/// labels.board.set_text(fps, "FPS: 240");
+/// labels.update();
+/// ```
+#[cfg(game_bindings)]
+#[derive(Debug, Default)]
+pub struct StatusLabels {
+    pub board: StatusBoard,
+    /// Z-order of the labels in the scene.
+    pub z_order: i32,
+    /// The labels are retained, so they survive their scene being replaced.
+    labels: std::collections::HashMap<StatusId, (crate::cocos2d::CCLabelBMFont, StatusEntry)>,
+}
+
+#[cfg(game_bindings)]
+impl StatusLabels {
+    pub fn new() -> Self {
+        Self {
+            z_order: 10000,
+            ..Default::default()
+        }
+    }
+
+    /// Syncs the labels with the board. Call it every frame, from the main thread.
+    ///
+    /// When the running scene changed, the labels are moved to the new one.
+    pub fn update(&mut self) {
+        use crate::{
+            cocos2d::{CCColor3B, CCDirector, CCLabelBMFont},
+            AddressUtils,
+        };
+
+        let director = CCDirector::shared();
+        let scene = director.running_scene();
+        if scene.is_null() {
+            return;
+        }
+
+        // labels that were removed from the board
+        self.labels.retain(|id, (label, _)| {
+            let keep = self.board.get(*id).is_some_and(|e| e.visible);
+            if !keep {
+                label.as_node().remove_from_parent(true);
+                label.as_node().release();
+            }
+            keep
+        });
+
+        let size = director.win_size();
+        for placement in self.board.layout((size.width, size.height)) {
+            let Some(entry) = self.board.get(placement.id) else {
+                continue;
+            };
+            let (label, shown) = match self.labels.get_mut(&placement.id) {
+                Some(label) => label,
+                None => {
+                    let label = CCLabelBMFont::create(&entry.text, &self.board.font);
+                    if label.is_null() {
+                        continue;
+                    }
+                    label.as_node().retain();
+                    label.set_scale(self.board.scale);
+                    label.set_color(CCColor3B::new(entry.color.0, entry.color.1, entry.color.2));
+                    label.set_opacity(entry.opacity);
+                    self.labels
+                        .entry(placement.id)
+                        .or_insert((label, entry.clone()))
+                }
+            };
+            if shown.text != entry.text {
+                label.set_string(&entry.text);
+            }
+            if shown.color != entry.color {
+                label.set_color(CCColor3B::new(entry.color.0, entry.color.1, entry.color.2));
+            }
+            if shown.opacity != entry.opacity {
+                label.set_opacity(entry.opacity);
+            }
+            *shown = entry.clone();
+            // new labels, or labels left on the previous scene (which may be
+            // pushed below this one)
+            if label.as_node().parent() != Some(scene) {
+                label.as_node().remove_from_parent(true);
+                scene.add_child(label.as_node(), self.z_order, -1);
+            }
+            label.set_anchor_point(placement.anchor);
+            label.as_node().set_position(placement.position);
+        }
+    }
+
+    /// Removes every label from the scene and releases them, e.g. when the mod
+    /// is unloaded. Call it before dropping the labels, from the main thread.
+    pub fn clear(&mut self) {
+        for (label, _) in self.labels.values() {
+            label.as_node().remove_from_parent(true);
+            label.as_node().release();
+        }
+        self.labels.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_stacks_corners() {
+        let mut board = StatusBoard {
+            margin: 5.0,
+            line_height: 10.0,
+            ..Default::default()
+        };
+        let fps = board.add(Corner::TopLeft, "FPS");
+        let cps = board.add(Corner::TopLeft, "CPS");
+        let attempt = board.add(Corner::BottomRight, "Attempt 1");
+        let noclip = board.add(Corner::BottomRight, "100%");
+
+        let layout = board.layout((100.0, 50.0));
+        let position = |id| layout.iter().find(|p| p.id == id).map(|p| p.position);
+        assert_eq!(position(fps), Some((5.0, 45.0)));
+        assert_eq!(position(cps), Some((5.0, 35.0)));
+        assert_eq!(position(attempt), Some((95.0, 5.0)));
+        assert_eq!(position(noclip), Some((95.0, 15.0)));
+        assert_eq!(layout[2].anchor, (1.0, 0.0));
+
+        // hidden labels make room for the next ones
+        board.set_visible(fps, false);
+        let layout = board.layout((100.0, 50.0));
+        assert_eq!(layout.len(), 3);
+        assert_eq!(layout[0].id, cps);
+        assert_eq!(layout[0].position, (5.0, 45.0));
+
+        assert!(board.remove(attempt));
+        assert!(!board.remove(attempt));
+        let layout = board.layout((100.0, 50.0));
+        assert_eq!(layout[1].id, noclip);
+        assert_eq!(layout[1].position, (95.0, 5.0));
+    }
+}
@@ -0,0 +1,515 @@
+//! Minimal RIFF/WAVE reader and writer.
+//!
+//! Only what the audio tools in this crate need: reading integer and float PCM
+//! into normalized `f32` samples, and writing 16-bit or 32-bit float PCM.
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+/// Errors that can happen while reading or writing WAV files.
+#[derive(Debug)]
+pub enum WavError {
+    /// An IO error.
+    Io(io::Error),
+    /// The file is not a valid RIFF/WAVE file.
+    InvalidHeader,
+    /// The file uses a sample format that is not supported.
+    UnsupportedFormat {
+        format_tag: u16,
+        bits_per_sample: u16,
+    },
+    /// The `fmt ` or `data` chunk is missing.
+    MissingChunk(&'static str),
+}
+
+impl std::fmt::Display for WavError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "io error: {e}"),
+            Self::InvalidHeader => write!(f, "not a RIFF/WAVE file"),
+            Self::UnsupportedFormat {
+                format_tag,
+                bits_per_sample,
+            } => write!(
+                f,
+                "unsupported sample format (format tag {format_tag}, {bits_per_sample} bits)"
+            ),
+            Self::MissingChunk(name) => write!(f, "missing `{name}` chunk"),
+        }
+    }
+}
+
+impl std::error::Error for WavError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for WavError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+const FORMAT_PCM: u16 = 1;
+const FORMAT_IEEE_FLOAT: u16 = 3;
+const FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// Sample encoding used when writing a WAV file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SampleFormat {
+    /// Signed 16-bit integer PCM.
+    #[default]
+    Int16,
+    /// 32-bit IEEE float PCM.
+    Float32,
+}
+
+impl SampleFormat {
+    #[inline]
+    const fn format_tag(self) -> u16 {
+        match self {
+            Self::Int16 => FORMAT_PCM,
+            Self::Float32 => FORMAT_IEEE_FLOAT,
+        }
+    }
+
+    #[inline]
+    const fn bytes_per_sample(self) -> u16 {
+        match self {
+            Self::Int16 => 2,
+            Self::Float32 => 4,
+        }
+    }
+}
+
+/// Decoded audio, interleaved and normalized to `[-1.0, 1.0]`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AudioBuffer {
+    pub sample_rate: u32,
+    pub channels: u16,
+    pub samples: Vec<f32>,
+}
+
+impl AudioBuffer {
+    /// Number of frames (samples per channel).
+    #[inline]
+    pub fn frames(&self) -> usize {
+        if self.channels == 0 {
+            0
+        } else {
+            self.samples.len() / self.channels as usize
+        }
+    }
+
+    /// Length of the buffer in seconds.
+    #[inline]
+    pub fn duration(&self) -> f64 {
+        if self.sample_rate == 0 {
+            0.0
+        } else {
+            self.frames() as f64 / self.sample_rate as f64
+        }
+    }
+
+    /// Returns a copy of this buffer converted to the given sample rate and channel count.
+    ///
+    /// Resampling is linear, which is plenty for short click samples.
+    pub fn convert(&self, sample_rate: u32, channels: u16) -> Self {
+        let src_frames = self.frames();
+        let src_channels = self.channels as usize;
+        if src_frames == 0
+            || src_channels == 0
+            || self.sample_rate == 0
+            || sample_rate == 0
+            || channels == 0
+        {
+            return Self {
+                sample_rate,
+                channels,
+                samples: Vec::new(),
+            };
+        }
+
+        let ratio = self.sample_rate as f64 / sample_rate as f64;
+        let dst_frames = ((src_frames as f64) / ratio).round() as usize;
+        let mut samples = Vec::with_capacity(dst_frames * channels as usize);
+
+        for frame in 0..dst_frames {
+            let pos = frame as f64 * ratio;
+            let i0 = (pos as usize).min(src_frames - 1);
+            let i1 = (i0 + 1).min(src_frames - 1);
+            let t = (pos - i0 as f64) as f32;
+
+            for ch in 0..channels as usize {
+                // mono sources are duplicated, extra source channels are dropped
+                let src_ch = ch.min(src_channels - 1);
+                let a = self.samples[i0 * src_channels + src_ch];
+                let b = self.samples[i1 * src_channels + src_ch];
+                samples.push(a + (b - a) * t);
+            }
+        }
+
+        Self {
+            sample_rate,
+            channels,
+            samples,
+        }
+    }
+}
+
+fn read_u16(bytes: &[u8], at: usize) -> u16 {
+    u16::from_le_bytes([bytes[at], bytes[at + 1]])
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+}
+
+/// Reads a WAV file from any reader into normalized `f32` samples.
+pub fn read<R: Read>(mut reader: R) -> Result<AudioBuffer, WavError> {
+    let mut header = [0u8; 12];
+    reader.read_exact(&mut header)?;
+    if &header[0..4] != b"RIFF" || &header[8..12] != b"WAVE" {
+        return Err(WavError::InvalidHeader);
+    }
+
+    let mut fmt: Option<(u16, u16, u32, u16)> = None;
+    loop {
+        let mut chunk_header = [0u8; 8];
+        match reader.read_exact(&mut chunk_header) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                return Err(WavError::MissingChunk(if fmt.is_none() {
+                    "fmt "
+                } else {
+                    "data"
+                }))
+            }
+            Err(e) => return Err(e.into()),
+        }
+        let id = &chunk_header[0..4];
+        let len = read_u32(&chunk_header, 4) as usize;
+
+        if id == b"fmt " {
+            // the length comes from the file, so don't allocate it up front
+            let mut body = Vec::new();
+            (&mut reader).take(len as u64).read_to_end(&mut body)?;
+            if body.len() < len || len < 16 {
+                return Err(WavError::InvalidHeader);
+            }
+            let mut format_tag = read_u16(&body, 0);
+            let channels = read_u16(&body, 2);
+            let sample_rate = read_u32(&body, 4);
+            let bits_per_sample = read_u16(&body, 14);
+            if channels == 0 || sample_rate == 0 {
+                return Err(WavError::InvalidHeader);
+            }
+            if format_tag == FORMAT_EXTENSIBLE && len >= 26 {
+                // the first two bytes of the subformat GUID are the actual format tag
+                format_tag = read_u16(&body, 24);
+            }
+            fmt = Some((format_tag, channels, sample_rate, bits_per_sample));
+        } else if id == b"data" {
+            let (format_tag, channels, sample_rate, bits_per_sample) =
+                fmt.ok_or(WavError::MissingChunk("fmt "))?;
+            // some writers leave the data length unset, so accept a short read here
+            let mut body = Vec::new();
+            (&mut reader).take(len as u64).read_to_end(&mut body)?;
+            let samples = decode_samples(&body, format_tag, bits_per_sample)?;
+            return Ok(AudioBuffer {
+                sample_rate,
+                channels,
+                samples,
+            });
+        } else {
+            // skip unknown chunks, which are padded to an even length
+            io::copy(
+                &mut (&mut reader).take(len as u64 + (len & 1) as u64),
+                &mut io::sink(),
+            )?;
+        }
+
+        if id == b"fmt " && len & 1 == 1 {
+            reader.read_exact(&mut [0u8; 1])?;
+        }
+    }
+}
+
+fn decode_samples(body: &[u8], format_tag: u16, bits: u16) -> Result<Vec<f32>, WavError> {
+    let unsupported = WavError::UnsupportedFormat {
+        format_tag,
+        bits_per_sample: bits,
+    };
+    let samples = match (format_tag, bits) {
+        (FORMAT_PCM, 8) => body.iter().map(|&b| (b as f32 - 128.0) / 128.0).collect(),
+        (FORMAT_PCM, 16) => body
+            .chunks_exact(2)
+            .map(|b| i16::from_le_bytes([b[0], b[1]]) as f32 / 32768.0)
+            .collect(),
+        (FORMAT_PCM, 24) => body
+            .chunks_exact(3)
+            .map(|b| (i32::from_le_bytes([0, b[0], b[1], b[2]]) >> 8) as f32 / 8388608.0)
+            .collect(),
+        (FORMAT_PCM, 32) => body
+            .chunks_exact(4)
+            .map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f32 / 2147483648.0)
+            .collect(),
+        (FORMAT_IEEE_FLOAT, 32) => body
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect(),
+        (FORMAT_IEEE_FLOAT, 64) => body
+            .chunks_exact(8)
+            .map(|b| f64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]) as f32)
+            .collect(),
+        _ => return Err(unsupported),
+    };
+    Ok(samples)
+}
+
+/// Reads a WAV file from disk.
+pub fn read_file<P: AsRef<Path>>(path: P) -> Result<AudioBuffer, WavError> {
+    read(BufReader::new(File::open(path)?))
+}
+
+/// Streaming WAV writer.
+///
+/// The header is written up front with placeholder sizes, which are patched
+/// in [`WavWriter::finalize`] (or on drop, ignoring errors).
+pub struct WavWriter<W: Write + Seek> {
+    writer: Option<W>,
+    format: SampleFormat,
+    channels: u16,
+    data_len: u32,
+}
+
+impl WavWriter<BufWriter<File>> {
+    /// Creates a WAV file on disk.
+    pub fn create<P: AsRef<Path>>(
+        path: P,
+        sample_rate: u32,
+        channels: u16,
+        format: SampleFormat,
+    ) -> Result<Self, WavError> {
+        Self::new(
+            BufWriter::new(File::create(path)?),
+            sample_rate,
+            channels,
+            format,
+        )
+    }
+}
+
+impl<W: Write + Seek> WavWriter<W> {
+    /// Writes the WAV header into `writer` and returns a writer ready for samples.
+    pub fn new(
+        mut writer: W,
+        sample_rate: u32,
+        channels: u16,
+        format: SampleFormat,
+    ) -> Result<Self, WavError> {
+        let block_align = channels * format.bytes_per_sample();
+        let byte_rate = sample_rate * block_align as u32;
+
+        writer.write_all(b"RIFF")?;
+        writer.write_all(&36u32.to_le_bytes())?; // patched on finalize
+        writer.write_all(b"WAVE")?;
+        writer.write_all(b"fmt ")?;
+        writer.write_all(&16u32.to_le_bytes())?;
+        writer.write_all(&format.format_tag().to_le_bytes())?;
+        writer.write_all(&channels.to_le_bytes())?;
+        writer.write_all(&sample_rate.to_le_bytes())?;
+        writer.write_all(&byte_rate.to_le_bytes())?;
+        writer.write_all(&block_align.to_le_bytes())?;
+        writer.write_all(&(format.bytes_per_sample() * 8).to_le_bytes())?;
+        writer.write_all(b"data")?;
+        writer.write_all(&0u32.to_le_bytes())?; // patched on finalize
+
+        Ok(Self {
+            writer: Some(writer),
+            format,
+            channels,
+            data_len: 0,
+        })
+    }
+
+    /// Number of channels this writer was created with.
+    #[inline]
+    pub fn channels(&self) -> u16 {
+        self.channels
+    }
+
+    /// Appends interleaved samples. Values outside `[-1.0, 1.0]` are clipped for integer output.
+    pub fn write_samples(&mut self, samples: &[f32]) -> Result<(), WavError> {
+        let writer = self.writer.as_mut().expect("writer already finalized");
+        match self.format {
+            SampleFormat::Int16 => {
+                for &s in samples {
+                    let v = (s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+                    writer.write_all(&v.to_le_bytes())?;
+                }
+            }
+            SampleFormat::Float32 => {
+                for &s in samples {
+                    writer.write_all(&s.to_le_bytes())?;
+                }
+            }
+        }
+        self.data_len = self
+            .data_len
+            .saturating_add(samples.len() as u32 * self.format.bytes_per_sample() as u32);
+        Ok(())
+    }
+
+    /// Patches the chunk sizes in the header and returns the inner writer.
+    pub fn finalize(mut self) -> Result<W, WavError> {
+        self.patch_header()?;
+        Ok(self.writer.take().unwrap())
+    }
+
+    fn patch_header(&mut self) -> Result<(), WavError> {
+        let Some(writer) = self.writer.as_mut() else {
+            return Ok(());
+        };
+        if self.data_len & 1 == 1 {
+            writer.write_all(&[0])?;
+        }
+        writer.seek(SeekFrom::Start(4))?;
+        writer.write_all(&(36 + self.data_len).to_le_bytes())?;
+        writer.seek(SeekFrom::Start(40))?;
+        writer.write_all(&self.data_len.to_le_bytes())?;
+        writer.seek(SeekFrom::End(0))?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<W: Write + Seek> Drop for WavWriter<W> {
+    fn drop(&mut self) {
+        let _ = self.patch_header();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn write(samples: &[f32], sample_rate: u32, channels: u16, format: SampleFormat) -> Vec<u8> {
+        let mut writer =
+            WavWriter::new(Cursor::new(Vec::new()), sample_rate, channels, format).unwrap();
+        writer.write_samples(samples).unwrap();
+        writer.finalize().unwrap().into_inner()
+    }
+
+    #[test]
+    fn round_trip() {
+        let samples = [0.0, 0.5, -0.5, 1.0, -1.0, 0.25];
+
+        let bytes = write(&samples, 48000, 2, SampleFormat::Float32);
+        assert_eq!(bytes.len(), 44 + samples.len() * 4);
+        let audio = read(Cursor::new(bytes)).unwrap();
+        assert_eq!((audio.sample_rate, audio.channels), (48000, 2));
+        assert_eq!(audio.samples, samples);
+        assert_eq!(audio.frames(), 3);
+
+        let audio = read(Cursor::new(write(&samples, 44100, 1, SampleFormat::Int16))).unwrap();
+        assert_eq!((audio.sample_rate, audio.channels), (44100, 1));
+        assert_eq!(audio.samples.len(), samples.len());
+        for (a, b) in audio.samples.iter().zip(samples) {
+            assert!((a - b).abs() < 1e-4, "{a} != {b}");
+        }
+    }
+
+    #[test]
+    fn skips_unknown_chunks() {
+        let bytes = write(&[0.5], 8000, 1, SampleFormat::Float32);
+        // insert an odd-sized chunk between `fmt ` and `data`
+        let mut patched = bytes[..36].to_vec();
+        patched.extend_from_slice(b"LIST");
+        patched.extend_from_slice(&3u32.to_le_bytes());
+        patched.extend_from_slice(&[1, 2, 3, 0]);
+        patched.extend_from_slice(&bytes[36..]);
+        assert_eq!(read(Cursor::new(patched)).unwrap().samples, [0.5]);
+    }
+
+    #[test]
+    fn invalid_files() {
+        assert!(matches!(
+            read(Cursor::new(b"RIFF\0\0\0\0WAVX".to_vec())),
+            Err(WavError::InvalidHeader)
+        ));
+        assert!(matches!(
+            read(Cursor::new(b"RIFF\0\0\0\0WAVE".to_vec())),
+            Err(WavError::MissingChunk("fmt "))
+        ));
+
+        // a huge chunk length in a short file is an error, not an allocation
+        let mut bytes = b"RIFF\0\0\0\0WAVEfmt ".to_vec();
+        bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+        bytes.extend_from_slice(&[0; 16]);
+        assert!(matches!(
+            read(Cursor::new(bytes)),
+            Err(WavError::InvalidHeader)
+        ));
+
+        // no channels, then no sample rate
+        let mut bytes = write(&[0.0], 8000, 1, SampleFormat::Int16);
+        bytes[22] = 0;
+        assert!(matches!(
+            read(Cursor::new(bytes)),
+            Err(WavError::InvalidHeader)
+        ));
+        let mut bytes = write(&[0.0], 8000, 1, SampleFormat::Int16);
+        bytes[24..28].copy_from_slice(&[0; 4]);
+        assert!(matches!(
+            read(Cursor::new(bytes)),
+            Err(WavError::InvalidHeader)
+        ));
+
+        let mut bytes = write(&[0.0], 8000, 1, SampleFormat::Int16);
+        bytes[34] = 12; // bits per sample
+        assert!(matches!(
+            read(Cursor::new(bytes)),
+            Err(WavError::UnsupportedFormat {
+                format_tag: FORMAT_PCM,
+                bits_per_sample: 12
+            })
+        ));
+    }
+
+    #[test]
+    fn convert() {
+        let mono = AudioBuffer {
+            sample_rate: 4,
+            channels: 1,
+            samples: vec![0.0, 1.0, 0.0, -1.0],
+        };
+        // doubling the rate interpolates between samples, mono is duplicated
+        let converted = mono.convert(8, 2);
+        assert_eq!(converted.frames(), 8);
+        assert_eq!(converted.duration(), 1.0);
+        let left: Vec<_> = converted.samples.iter().step_by(2).copied().collect();
+        assert_eq!(left, [0.0, 0.5, 1.0, 0.5, 0.0, -0.5, -1.0, -1.0]);
+        assert_eq!(converted.samples[0..4], [0.0, 0.0, 0.5, 0.5]);
+
+        // halving it keeps every other sample, extra channels are dropped
+        let stereo = AudioBuffer {
+            sample_rate: 8,
+            channels: 2,
+            samples: converted.samples,
+        };
+        assert_eq!(stereo.convert(4, 1).samples, [0.0, 1.0, 0.0, -1.0]);
+        assert!(AudioBuffer::default().convert(44100, 2).samples.is_empty());
+        let no_rate = AudioBuffer {
+            sample_rate: 0,
+            ..mono
+        };
+        assert!(no_rate.convert(44100, 2).samples.is_empty());
+    }
+}